@@ -10,6 +10,9 @@ use quote::quote;
 use syn::parse::{self, Parse, ParseStream};
 use syn::punctuated::Punctuated;
 
+mod lists;
+use crate::lists::ListInput;
+
 static INCREMENTAL_ID_COUNTER: AtomicUsize = AtomicUsize::new(0);
 
 #[cfg_attr(feature = "extra-traits", derive(Debug))]
@@ -33,6 +36,9 @@ enum LabelMeta {
     CustomName(pm2::Literal),
     AssocType(syn::Type),
     CratePath(syn::Path),
+    Default(syn::Expr),
+    Validate(syn::Path),
+    Nested,
 }
 
 impl Parse for LabelMeta {
@@ -48,23 +54,36 @@ impl Parse for LabelMeta {
             let meta_name: syn::Ident = input.parse()?;
             (meta_name.span(), meta_name.to_string())
         };
-        let _: Token![=] = input.parse()?;
 
         const LABEL_NAME_ID: &str = "name";
         const CRATE_ID: &str = "crate";
+        const DEFAULT_ID: &str = "default";
+        const VALIDATE_ID: &str = "validate";
+        const NESTED_ID: &str = "nested";
         const TYPE_IDS: [&str; 2] = ["type", "assoc_type"];
 
+        // flag options don't take a value
+        if meta_name_str == NESTED_ID {
+            return Ok(LabelMeta::Nested);
+        }
+        let _: Token![=] = input.parse()?;
+
         match &meta_name_str[..] {
             LABEL_NAME_ID => input.parse().map(LabelMeta::CustomName),
             CRATE_ID => input.parse().map(LabelMeta::CratePath),
+            DEFAULT_ID => input.parse().map(LabelMeta::Default),
+            VALIDATE_ID => input.parse().map(LabelMeta::Validate),
             s if TYPE_IDS.contains(&s) => input.parse().map(LabelMeta::AssocType),
             _ => Err(syn::Error::new(
                 span,
                 format![
-                    "expected {}, {}, or {}",
+                    "expected {}, {}, {}, {}, {}, or {}",
                     TYPE_IDS.join(", "),
                     CRATE_ID,
-                    LABEL_NAME_ID
+                    LABEL_NAME_ID,
+                    DEFAULT_ID,
+                    VALIDATE_ID,
+                    NESTED_ID
                 ],
             )),
         }
@@ -76,6 +95,9 @@ struct LabelOptions {
     name: Option<pm2::Literal>,
     assoc_type: Option<syn::Type>,
     crate_path: Option<syn::Path>,
+    default: Option<syn::Expr>,
+    validate: Option<syn::Path>,
+    nested: bool,
 }
 
 impl Parse for LabelOptions {
@@ -85,6 +107,9 @@ impl Parse for LabelOptions {
             name: None,
             assoc_type: None,
             crate_path: None,
+            default: None,
+            validate: None,
+            nested: false,
         };
         for meta in &metas {
             match meta {
@@ -97,6 +122,15 @@ impl Parse for LabelOptions {
                 LabelMeta::CratePath(path) => {
                     opts.crate_path = Some(path.clone());
                 }
+                LabelMeta::Default(expr) => {
+                    opts.default = Some(expr.clone());
+                }
+                LabelMeta::Validate(path) => {
+                    opts.validate = Some(path.clone());
+                }
+                LabelMeta::Nested => {
+                    opts.nested = true;
+                }
             }
         }
         Ok(opts)
@@ -110,6 +144,82 @@ pub fn label(attr: TokenStream, item: TokenStream) -> TokenStream {
     impl_label(&label_options, &label_struct)
 }
 
+#[doc(hidden)]
+#[proc_macro]
+pub fn __lhlist(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as ListInput<lists::LabelValue>);
+    lists::expand_lhlist(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+#[doc(hidden)]
+#[proc_macro]
+pub fn __cons(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as ListInput<syn::Expr>);
+    lists::expand_cons(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+#[doc(hidden)]
+#[proc_macro]
+pub fn __labels(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as ListInput<lists::TypedLabel>);
+    lists::expand_labels(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+#[doc(hidden)]
+#[proc_macro]
+#[allow(non_snake_case)]
+pub fn __Labels(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as ListInput<lists::TypedLabel>);
+    lists::expand_labels_type(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+#[doc(hidden)]
+#[proc_macro]
+#[allow(non_snake_case)]
+pub fn __LHList(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as ListInput<lists::TypedLabel>);
+    lists::expand_lhlist_type(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+#[doc(hidden)]
+#[proc_macro]
+#[allow(non_snake_case)]
+pub fn __Coprod(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as ListInput<lists::TypedLabel>);
+    lists::expand_coprod_type(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+#[doc(hidden)]
+#[proc_macro]
+#[allow(non_snake_case)]
+pub fn __StrLabel(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as lists::StrLabelInput);
+    lists::expand_str_label(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+#[doc(hidden)]
+#[proc_macro]
+pub fn __let_lhlist(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as lists::LetInput);
+    lists::expand_let(input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
 fn impl_label(label_options: &LabelOptions, label_struct: &LabelStruct) -> TokenStream {
     // struct name
     let name = &label_struct.name;
@@ -121,7 +231,7 @@ fn impl_label(label_options: &LabelOptions, label_struct: &LabelStruct) -> Token
 
     // unique identifier
     let id = INCREMENTAL_ID_COUNTER.fetch_add(1, Ordering::SeqCst);
-    let id_ty = generate_uint(id);
+    let id_ty = generate_uint(&quote! { typenum }, id as u64);
 
     // attributes
     let attrs = &label_struct.attrs;
@@ -132,7 +242,63 @@ fn impl_label(label_options: &LabelOptions, label_struct: &LabelStruct) -> Token
         None => quote! { () },
     };
 
-    let dummy_const = syn::Ident::new(&format!("_IMPL_LABEL_FOR_{}", name), pm2::Span::call_site());
+    // default value
+    let label_default = match label_options.default {
+        Some(ref expr) => quote! {
+            impl _lhlist::LabelDefaultSource for #name {
+                type Explicit = _lhlist::True;
+            }
+            impl _lhlist::LabelDefaultMatch<_lhlist::True> for #name {
+                fn match_default_value() -> #assoc_type {
+                    #expr
+                }
+            }
+        },
+        // labels without an explicit default use the associated type's `Default` implementation
+        // (if any) through a blanket implementation in lhlist
+        None => quote! {
+            impl _lhlist::LabelDefaultSource for #name {
+                type Explicit = _lhlist::False;
+            }
+        },
+    };
+
+    // validation
+    let label_validate = match label_options.validate {
+        Some(ref path) => quote! {
+            impl _lhlist::LabelValidate for #name {
+                fn validate(value: &#assoc_type) -> ::std::result::Result<(), ::std::string::String> {
+                    #path(value).map_err(|err| ::std::string::ToString::to_string(&err))
+                }
+            }
+        },
+        None => quote! {
+            impl _lhlist::LabelValidate for #name {}
+        },
+    };
+
+    // parsing; method resolution on the concrete associated type picks the `FromStr` parser if the
+    // type implements `FromStr` (or is an `Option` of such a type), and otherwise falls back to
+    // returning `None`
+    let label_from_str = quote! {
+        impl _lhlist::LabelFromStr for #name {
+            fn parse_value(
+                s: &str,
+            ) -> ::std::option::Option<::std::result::Result<#assoc_type, ::std::string::String>> {
+                use _lhlist::{__ParseFromStr, __ParseOption, __ParseUnsupported};
+                let probe = _lhlist::__FromStrProbe::<#assoc_type>(::std::marker::PhantomData);
+                (&probe).parse_value(s)
+            }
+        }
+    };
+
+    // nesting
+    let nested = if label_options.nested {
+        quote! { _lhlist::True }
+    } else {
+        quote! { _lhlist::False }
+    };
+
     let use_lhlist = match label_options.crate_path {
         Some(ref path) => quote! {
             use #path as _lhlist;
@@ -146,21 +312,28 @@ fn impl_label(label_options: &LabelOptions, label_struct: &LabelStruct) -> Token
         #(#attrs)*
         #[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
         struct #name;
-        const #dummy_const: () = {
+        const _: () = {
             #use_lhlist
             impl _lhlist::Label for #name {
                 const NAME: &'static str = #name_str;
                 type AssocType = #assoc_type;
                 type Uid = #id_ty;
             }
+            #label_default
+            #label_validate
+            #label_from_str
+            impl _lhlist::LabelNested for #name {
+                type Nested = #nested;
+            }
         };
     };
     generated.into()
 }
 
 fn generate_uint_recurse(
-    target: usize,
-    curr_val: usize,
+    typenum: &pm2::TokenStream,
+    target: u64,
+    curr_val: u64,
     curr_toks: pm2::TokenStream,
 ) -> pm2::TokenStream {
     if curr_val == 0 {
@@ -168,25 +341,28 @@ fn generate_uint_recurse(
     } else {
         // compute most significant bit
         let bit = if target & curr_val > 0 {
-            quote! { typenum::B1 }
+            quote! { #typenum::B1 }
         } else {
-            quote! { typenum::B0 }
+            quote! { #typenum::B0 }
         };
         // add most significant bit and recurse to add rest
         generate_uint_recurse(
+            typenum,
             target,
             curr_val >> 1,
-            quote! { typenum::UInt<#curr_toks, #bit> },
+            quote! { #typenum::UInt<#curr_toks, #bit> },
         )
     }
 }
 
-fn generate_uint(value: usize) -> pm2::TokenStream {
+/// Generates the `typenum` unsigned integer type for `value`, where `typenum` is the path to the
+/// `typenum` crate.
+fn generate_uint(typenum: &pm2::TokenStream, value: u64) -> pm2::TokenStream {
+    // start at the most significant set bit
     let start = if value > 0 {
-        value.next_power_of_two()
+        1 << (63 - value.leading_zeros())
     } else {
         0
     };
-    let gen = generate_uint_recurse(value, start, quote! { typenum::UTerm });
-    gen
+    generate_uint_recurse(typenum, value, start, quote! { #typenum::UTerm })
 }
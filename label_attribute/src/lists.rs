@@ -0,0 +1,505 @@
+use std::collections::HashSet;
+
+use quote::{quote, quote_spanned};
+use syn::parse::{self, Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+
+/// Path to the `lhlist` crate, passed as the first argument of the list macros by the
+/// `macro_rules!` wrappers in `lhlist` (i.e. `$crate`), and terminated by a `;`.
+#[cfg_attr(feature = "extra-traits", derive(Debug))]
+pub struct CratePath(pm2::TokenStream);
+
+impl Parse for CratePath {
+    fn parse(input: ParseStream) -> parse::Result<Self> {
+        let mut path = pm2::TokenStream::new();
+        while !input.peek(Token![;]) {
+            let tt: pm2::TokenTree = input.parse()?;
+            path.extend(Some(tt));
+        }
+        let _: Token![;] = input.parse()?;
+        Ok(CratePath(path))
+    }
+}
+
+/// Input of a list macro: the crate path followed by a comma-separated list of items.
+#[cfg_attr(feature = "extra-traits", derive(Debug))]
+pub struct ListInput<T> {
+    krate: CratePath,
+    items: Punctuated<T, syn::token::Comma>,
+}
+
+impl<T: Parse> Parse for ListInput<T> {
+    fn parse(input: ParseStream) -> parse::Result<Self> {
+        let krate = input.parse()?;
+        let items = input.parse_terminated(T::parse)?;
+        Ok(ListInput { krate, items })
+    }
+}
+
+/// Maximum length (in bytes) of a string label; must match `lhlist::MAX_STR_LABEL_LEN` (the
+/// `lhlist::StrLabel` tests check labels at and over the limit).
+const MAX_STR_LABEL_LEN: usize = 64;
+
+/// A label in a list macro: either a label type, or a string literal denoting a string label.
+#[cfg_attr(feature = "extra-traits", derive(Debug))]
+pub enum LabelSpec {
+    Type(syn::Type),
+    Str(syn::LitStr),
+}
+
+impl Parse for LabelSpec {
+    fn parse(input: ParseStream) -> parse::Result<Self> {
+        if input.peek(syn::LitStr) {
+            input.parse().map(LabelSpec::Str)
+        } else {
+            input.parse().map(LabelSpec::Type)
+        }
+    }
+}
+
+impl LabelSpec {
+    /// Key used to detect duplicate labels.
+    fn key(&self) -> String {
+        match self {
+            LabelSpec::Type(ty) => quote! { #ty }.to_string(),
+            LabelSpec::Str(lit) => format!["{:?}", lit.value()],
+        }
+    }
+
+    fn span(&self) -> pm2::Span {
+        match self {
+            LabelSpec::Type(ty) => ty.span(),
+            LabelSpec::Str(lit) => lit.span(),
+        }
+    }
+
+    /// Generates the label type, using `assoc` as the associated type of string labels.
+    fn to_type(
+        &self,
+        krate: &pm2::TokenStream,
+        assoc: pm2::TokenStream,
+    ) -> parse::Result<pm2::TokenStream> {
+        match self {
+            LabelSpec::Type(ty) => Ok(quote! { #ty }),
+            LabelSpec::Str(lit) => str_label_type(krate, lit, assoc),
+        }
+    }
+}
+
+/// 64-bit FNV-1a hash, used to generate string label identifiers.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Generates the type of the string label `lit` with associated type `assoc`.
+///
+/// The label's identifier is a hash of the label name with the most significant bit set, which
+/// keeps it disjoint from the incremental identifiers of `#[label]` labels.
+pub fn str_label_type(
+    krate: &pm2::TokenStream,
+    lit: &syn::LitStr,
+    assoc: pm2::TokenStream,
+) -> parse::Result<pm2::TokenStream> {
+    let name = lit.value();
+    if name.len() > MAX_STR_LABEL_LEN {
+        return Err(syn::Error::new(
+            lit.span(),
+            format![
+                "string label is longer than the maximum of {} bytes",
+                MAX_STR_LABEL_LEN
+            ],
+        ));
+    }
+    let uid = crate::generate_uint(
+        &quote! { #krate::typenum },
+        fnv1a(name.as_bytes()) | 1 << 63,
+    );
+    let chars = fold_list(name.chars(), quote! { #krate::Nil }, |c, tail| {
+        quote! { #krate::Cons<#krate::Ch<#c>, #tail> }
+    });
+    Ok(quote! { #krate::StrLabel<#krate::TStr<#uid, #chars>, #assoc> })
+}
+
+/// A label along with an optional associated type (for string labels), as used in the type-level
+/// list macros: `<label>` or `"<name>": <type>`.
+#[cfg_attr(feature = "extra-traits", derive(Debug))]
+pub struct TypedLabel {
+    label: LabelSpec,
+    assoc: Option<syn::Type>,
+}
+
+impl Parse for TypedLabel {
+    fn parse(input: ParseStream) -> parse::Result<Self> {
+        let label = input.parse()?;
+        let assoc = match label {
+            LabelSpec::Str(_) if input.peek(Token![:]) => {
+                let _: Token![:] = input.parse()?;
+                Some(input.parse()?)
+            }
+            _ => None,
+        };
+        Ok(TypedLabel { label, assoc })
+    }
+}
+
+impl TypedLabel {
+    fn to_type(&self, krate: &pm2::TokenStream) -> parse::Result<pm2::TokenStream> {
+        let assoc = match self.assoc {
+            Some(ref ty) => quote! { #ty },
+            None => quote! { () },
+        };
+        self.label.to_type(krate, assoc)
+    }
+}
+
+/// Input of the `StrLabel!` macro: the crate path followed by a typed string label.
+#[cfg_attr(feature = "extra-traits", derive(Debug))]
+pub struct StrLabelInput {
+    krate: CratePath,
+    label: TypedLabel,
+}
+
+impl Parse for StrLabelInput {
+    fn parse(input: ParseStream) -> parse::Result<Self> {
+        let krate = input.parse()?;
+        let lit = input.parse()?;
+        let assoc = if input.peek(Token![:]) {
+            let _: Token![:] = input.parse()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        let label = TypedLabel {
+            label: LabelSpec::Str(lit),
+            assoc,
+        };
+        Ok(StrLabelInput { krate, label })
+    }
+}
+
+pub fn expand_str_label(input: StrLabelInput) -> parse::Result<pm2::TokenStream> {
+    input.label.to_type(&input.krate.0)
+}
+
+/// A `<label> = <value>` assignment in an `lhlist!` invocation.
+#[cfg_attr(feature = "extra-traits", derive(Debug))]
+pub struct LabelValue {
+    label: LabelSpec,
+    value: syn::Expr,
+}
+
+impl Parse for LabelValue {
+    fn parse(input: ParseStream) -> parse::Result<Self> {
+        let label = input.parse()?;
+        let _: Token![=] = input.parse()?;
+        let value = input.parse()?;
+        Ok(LabelValue { label, value })
+    }
+}
+
+/// Checks that no label appears more than once in a list, reporting an error at the location of
+/// the first repeated label.
+fn check_duplicates<'a, I>(labels: I) -> parse::Result<()>
+where
+    I: Iterator<Item = &'a LabelSpec>,
+{
+    let mut seen = HashSet::new();
+    for label in labels {
+        let key = label.key();
+        if !seen.insert(key.clone()) {
+            return Err(syn::Error::new(
+                label.span(),
+                format!["duplicate label `{}` in list", key],
+            ));
+        }
+    }
+    Ok(())
+}
+
+/// Builds a nested cons-list from items, starting from the end of the list.
+fn fold_list<I, F>(items: I, end: pm2::TokenStream, mut cons: F) -> pm2::TokenStream
+where
+    I: IntoIterator,
+    F: FnMut(I::Item, pm2::TokenStream) -> pm2::TokenStream,
+{
+    let items: Vec<_> = items.into_iter().collect();
+    items
+        .into_iter()
+        .rev()
+        .fold(end, |tail, item| cons(item, tail))
+}
+
+/// Builds a nested cons-list from items, where generating an element can fail.
+fn try_fold_list<I, F>(
+    items: I,
+    end: pm2::TokenStream,
+    mut cons: F,
+) -> parse::Result<pm2::TokenStream>
+where
+    I: IntoIterator,
+    F: FnMut(I::Item, pm2::TokenStream) -> parse::Result<pm2::TokenStream>,
+{
+    let items: Vec<_> = items.into_iter().collect();
+    items
+        .into_iter()
+        .rev()
+        .try_fold(end, |tail, item| cons(item, tail))
+}
+
+pub fn expand_lhlist(input: ListInput<LabelValue>) -> parse::Result<pm2::TokenStream> {
+    check_duplicates(input.items.iter().map(|field| &field.label))?;
+    let krate = &input.krate.0;
+    try_fold_list(input.items.iter(), quote! { #krate::Nil }, |field, tail| {
+        let LabelValue { label, value } = field;
+        // associated types of string labels are inferred from the value
+        let label = label.to_type(krate, quote! { _ })?;
+        let head = quote_spanned! { value.span()=>
+            #krate::LabeledValue::<#label>::new(#value)
+        };
+        Ok(quote! { #krate::Cons { head: #head, tail: #tail } })
+    })
+}
+
+pub fn expand_cons(input: ListInput<syn::Expr>) -> parse::Result<pm2::TokenStream> {
+    let krate = &input.krate.0;
+    Ok(fold_list(
+        input.items.iter(),
+        quote! { #krate::Nil },
+        |value, tail| quote! { #krate::cons(#value, #tail) },
+    ))
+}
+
+pub fn expand_labels(input: ListInput<TypedLabel>) -> parse::Result<pm2::TokenStream> {
+    check_duplicates(input.items.iter().map(|item| &item.label))?;
+    let krate = &input.krate.0;
+    try_fold_list(input.items.iter(), quote! { #krate::Nil }, |label, tail| {
+        let span = label.label.span();
+        let label = label.to_type(krate)?;
+        let head = quote_spanned! { span=>
+            ::std::marker::PhantomData::<#label>
+        };
+        Ok(quote! { #krate::Cons { head: #head, tail: #tail } })
+    })
+}
+
+pub fn expand_labels_type(input: ListInput<TypedLabel>) -> parse::Result<pm2::TokenStream> {
+    check_duplicates(input.items.iter().map(|item| &item.label))?;
+    let krate = &input.krate.0;
+    try_fold_list(input.items.iter(), quote! { #krate::Nil }, |label, tail| {
+        let label = label.to_type(krate)?;
+        Ok(quote! { #krate::LCons<#label, #tail> })
+    })
+}
+
+pub fn expand_lhlist_type(input: ListInput<TypedLabel>) -> parse::Result<pm2::TokenStream> {
+    check_duplicates(input.items.iter().map(|item| &item.label))?;
+    let krate = &input.krate.0;
+    try_fold_list(input.items.iter(), quote! { #krate::Nil }, |label, tail| {
+        let label = label.to_type(krate)?;
+        Ok(quote! { #krate::LVCons<#label, #tail> })
+    })
+}
+
+pub fn expand_coprod_type(input: ListInput<TypedLabel>) -> parse::Result<pm2::TokenStream> {
+    check_duplicates(input.items.iter().map(|item| &item.label))?;
+    let krate = &input.krate.0;
+    try_fold_list(
+        input.items.iter(),
+        quote! { #krate::CNil },
+        |label, tail| {
+            let label = label.to_type(krate)?;
+            Ok(quote! { #krate::LVCoprod<#label, #tail> })
+        },
+    )
+}
+
+/// A `<label>: <pattern>` binding in a `let_lhlist!` invocation.
+#[cfg_attr(feature = "extra-traits", derive(Debug))]
+pub struct LabelPat {
+    label: LabelSpec,
+    pat: syn::Pat,
+}
+
+impl Parse for LabelPat {
+    fn parse(input: ParseStream) -> parse::Result<Self> {
+        let label = input.parse()?;
+        let _: Token![:] = input.parse()?;
+        let pat = input.parse()?;
+        Ok(LabelPat { label, pat })
+    }
+}
+
+/// Handling of the elements not bound in a `let_lhlist!` destructuring.
+#[cfg_attr(feature = "extra-traits", derive(Debug))]
+pub enum Rest {
+    /// No `..`: all elements must be bound
+    Exhaustive,
+    /// `..`: remaining elements are ignored
+    Ignore,
+    /// `..<pattern>`: remaining elements are bound as a list
+    Bind(syn::Pat),
+}
+
+/// A `{ <label>: <pattern>, .. } = <expr>;` destructuring statement.
+#[cfg_attr(feature = "extra-traits", derive(Debug))]
+pub struct Destructure {
+    fields: Vec<LabelPat>,
+    rest: Rest,
+    expr: syn::Expr,
+}
+
+impl Parse for Destructure {
+    fn parse(input: ParseStream) -> parse::Result<Self> {
+        let content;
+        braced!(content in input);
+        let mut fields = vec![];
+        let mut rest = Rest::Exhaustive;
+        while !content.is_empty() {
+            if content.peek(Token![..]) {
+                let _: Token![..] = content.parse()?;
+                rest = if content.is_empty() || content.peek(Token![,]) {
+                    Rest::Ignore
+                } else {
+                    Rest::Bind(content.parse()?)
+                };
+                if content.peek(Token![,]) {
+                    let _: Token![,] = content.parse()?;
+                }
+                if !content.is_empty() {
+                    return Err(content.error("`..` must be the last item of the pattern"));
+                }
+                break;
+            }
+            fields.push(content.parse()?);
+            if content.is_empty() {
+                break;
+            }
+            let _: Token![,] = content.parse()?;
+        }
+        let _: Token![=] = input.parse()?;
+        let expr = input.parse()?;
+        let _: Token![;] = input.parse()?;
+        Ok(Destructure { fields, rest, expr })
+    }
+}
+
+/// Input of the `let_lhlist!` macro: the crate path followed by destructuring statements.
+#[cfg_attr(feature = "extra-traits", derive(Debug))]
+pub struct LetInput {
+    krate: CratePath,
+    stmts: Vec<Destructure>,
+}
+
+impl Parse for LetInput {
+    fn parse(input: ParseStream) -> parse::Result<Self> {
+        let krate = input.parse()?;
+        let mut stmts = vec![];
+        while !input.is_empty() {
+            stmts.push(input.parse()?);
+        }
+        Ok(LetInput { krate, stmts })
+    }
+}
+
+pub fn expand_let(input: LetInput) -> parse::Result<pm2::TokenStream> {
+    let krate = &input.krate.0;
+    let rest_ident = syn::Ident::new("__lhlist_rest", pm2::Span::call_site());
+    let elem_ident = syn::Ident::new("__lhlist_elem", pm2::Span::call_site());
+
+    let mut output = pm2::TokenStream::new();
+    for stmt in &input.stmts {
+        check_duplicates(stmt.fields.iter().map(|field| &field.label))?;
+        let expr = &stmt.expr;
+        // statements with `ref` / `ref mut` bindings destructure a view of references to the
+        // values of the list, instead of consuming the list
+        let by_ref = stmt
+            .fields
+            .iter()
+            .filter_map(|field| ref_binding(&field.pat));
+        let (borrowed, borrowed_mut) = by_ref.fold((false, false), |(_, any_mut), is_mut| {
+            (true, any_mut || is_mut)
+        });
+        // only bindings which match references explicitly may be mixed with `ref` bindings, since
+        // other patterns would silently bind references instead of values
+        if borrowed {
+            if let Some(field) = stmt.fields.iter().find(|field| binds_by_value(&field.pat)) {
+                return Err(syn::Error::new_spanned(
+                    &field.pat,
+                    "cannot mix `ref` and by-value bindings in one `let_lhlist!` statement",
+                ));
+            }
+        }
+        output.extend(if borrowed_mut {
+            quote! { let #rest_ident = #krate::AsRefs::as_mut_refs(&mut #expr); }
+        } else if borrowed {
+            quote! { let #rest_ident = #krate::AsRefs::as_refs(&#expr); }
+        } else {
+            quote! { let #rest_ident = #expr; }
+        });
+        for LabelPat { label, pat } in &stmt.fields {
+            let span = label.span();
+            let label = label.to_type(krate, quote! { () })?;
+            let pluck = quote_spanned! { span=>
+                #krate::Pluck::<#label>::pluck(#rest_ident)
+            };
+            // `ref` bindings in a statement with `ref mut` bindings reborrow immutably
+            let value = if borrowed_mut && ref_binding(pat) == Some(false) {
+                quote! { &*#elem_ident.value }
+            } else {
+                quote! { #elem_ident.value }
+            };
+            let pat = if borrowed {
+                strip_ref_binding(pat)
+            } else {
+                pat.clone()
+            };
+            output.extend(quote! {
+                let (#elem_ident, #rest_ident) = #pluck;
+                let #pat = #value;
+            });
+        }
+        output.extend(match stmt.rest {
+            Rest::Exhaustive => quote! { let #krate::Nil = #rest_ident; },
+            Rest::Ignore => quote! { let _ = #rest_ident; },
+            Rest::Bind(ref pat) => quote! { let #pat = #rest_ident; },
+        });
+    }
+    Ok(output)
+}
+
+/// Returns `Some(is_mut)` if `pat` is a `ref` or `ref mut` binding, and `None` otherwise.
+fn ref_binding(pat: &syn::Pat) -> Option<bool> {
+    match pat {
+        syn::Pat::Ident(pat_ident) if pat_ident.by_ref.is_some() => {
+            Some(pat_ident.mutability.is_some())
+        }
+        _ => None,
+    }
+}
+
+/// Returns `true` if `pat` binds (part of) a value by value, i.e. it isn't a `ref` / `ref mut`
+/// binding, a wildcard, or a reference pattern.
+fn binds_by_value(pat: &syn::Pat) -> bool {
+    match pat {
+        syn::Pat::Ident(pat_ident) => pat_ident.by_ref.is_none(),
+        syn::Pat::Wild(_) | syn::Pat::Ref(_) => false,
+        _ => true,
+    }
+}
+
+/// Removes `ref` / `ref mut` from a binding, so that it binds a reference from a view of references
+/// directly.
+fn strip_ref_binding(pat: &syn::Pat) -> syn::Pat {
+    match pat {
+        syn::Pat::Ident(pat_ident) if pat_ident.by_ref.is_some() => {
+            let mut pat_ident = pat_ident.clone();
+            pat_ident.by_ref = None;
+            pat_ident.mutability = None;
+            syn::Pat::Ident(pat_ident)
+        }
+        _ => pat.clone(),
+    }
+}
@@ -8,5 +8,5 @@ struct MyLabel;
 
 fn main() {
     println!("{}", MyLabel::name());
-    println!("{}", <MyLabel as Label>::AssocType::max_value());
+    println!("{}", <MyLabel as Label>::AssocType::MAX);
 }
@@ -0,0 +1,183 @@
+use std::marker::PhantomData;
+
+use crate::cons::{Cons, LCons, LVCons, Nil};
+use crate::label::{Label, LabeledValue};
+use crate::lookup::Pluck;
+use crate::relation::{False, Member, True};
+
+/// Type-state builder for a label-value cons-list of labels `Labels` (an [LCons](type.LCons.html)
+/// label-only cons-list, typically created with the [Labels](macro.Labels.html) macro).
+///
+/// Values can be set in any order. `set` only compiles for labels in `Labels` which have not
+/// already been set, and `build` only compiles once every label has been set. The resulting
+/// [LVCons](type.LVCons.html) is in the order of `Labels`.
+///
+/// ## Example
+/// ```
+/// # #[macro_use] extern crate lhlist;
+/// use lhlist::RecordBuilder;
+/// # fn main() {
+/// new_label![Name: &'static str];
+/// new_label![Age: u8];
+/// new_label![Admin: bool];
+///
+/// let person = RecordBuilder::<Labels![Name, Age, Admin]>::new()
+///     .set::<Age>(37)
+///     .set::<Admin>(false)
+///     .set::<Name>("Jane")
+///     .build();
+/// assert_eq!(person, lhlist![Name = "Jane", Age = 37, Admin = false]);
+/// # }
+/// ```
+///
+/// Missing labels are a compile-time error:
+/// ```compile_fail
+/// # #[macro_use] extern crate lhlist;
+/// # use lhlist::RecordBuilder;
+/// # fn main() {
+/// # new_label![Name: &'static str];
+/// # new_label![Age: u8];
+/// let person = RecordBuilder::<Labels![Name, Age]>::new()
+///     .set::<Age>(37)
+///     .build();
+/// # }
+/// ```
+///
+/// As is setting a label twice:
+/// ```compile_fail
+/// # #[macro_use] extern crate lhlist;
+/// # use lhlist::RecordBuilder;
+/// # fn main() {
+/// # new_label![Name: &'static str];
+/// # new_label![Age: u8];
+/// let person = RecordBuilder::<Labels![Name, Age]>::new()
+///     .set::<Age>(37)
+///     .set::<Name>("Jane")
+///     .set::<Age>(38)
+///     .build();
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordBuilder<Labels, Set = Nil> {
+    values: Set,
+    _labels: PhantomData<Labels>,
+}
+
+impl<Labels> RecordBuilder<Labels, Nil> {
+    /// Creates a new builder, with no values set.
+    pub fn new() -> Self {
+        RecordBuilder {
+            values: Nil,
+            _labels: PhantomData,
+        }
+    }
+}
+
+impl<Labels> Default for RecordBuilder<Labels, Nil> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Labels, Set> RecordBuilder<Labels, Set> {
+    /// Sets the value for a label. The label must be one of `Labels`, and must not have already
+    /// been set.
+    pub fn set<TargetL>(
+        self,
+        value: TargetL::AssocType,
+    ) -> RecordBuilder<Labels, LVCons<TargetL, Set>>
+    where
+        TargetL: Label,
+        Labels: Member<TargetL, Output = True>,
+        Set: Member<TargetL, Output = False>,
+    {
+        RecordBuilder {
+            values: Cons {
+                head: LabeledValue::new(value),
+                tail: self.values,
+            },
+            _labels: PhantomData,
+        }
+    }
+
+    /// Builds the label-value cons-list, in the order of `Labels`. Only available once every label
+    /// has been set.
+    pub fn build(self) -> <Set as Reorder<Labels>>::Output
+    where
+        Set: Reorder<Labels>,
+    {
+        self.values.reorder()
+    }
+}
+
+/// Reorders a cons-list into the order of the labels in `Labels`. `Labels` must contain exactly
+/// the labels in the list.
+pub trait Reorder<Labels> {
+    /// The reordered cons-list
+    type Output;
+    /// Reorders the list
+    fn reorder(self) -> Self::Output;
+}
+
+impl Reorder<Nil> for Nil {
+    type Output = Nil;
+
+    fn reorder(self) -> Nil {
+        Nil
+    }
+}
+
+impl<List, L, T> Reorder<LCons<L, T>> for List
+where
+    List: Pluck<L>,
+    <List as Pluck<L>>::Remainder: Reorder<T>,
+{
+    type Output =
+        Cons<<List as Pluck<L>>::Elem, <<List as Pluck<L>>::Remainder as Reorder<T>>::Output>;
+
+    fn reorder(self) -> Self::Output {
+        let (head, remainder) = self.pluck();
+        Cons {
+            head,
+            tail: remainder.reorder(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[label(type=u8, crate=crate)]
+    struct Label1;
+    #[label(type=String, crate=crate)]
+    struct Label2;
+    #[label(type=bool, crate=crate)]
+    struct Label3;
+
+    #[test]
+    fn builder() {
+        type TestLabels = Labels![Label1, Label2, Label3];
+        let expected = lhlist![Label1 = 4, Label2 = "foo".to_string(), Label3 = true];
+
+        let built = RecordBuilder::<TestLabels>::new()
+            .set::<Label1>(4)
+            .set::<Label2>("foo".to_string())
+            .set::<Label3>(true)
+            .build();
+        assert_eq!(built, expected);
+
+        let built = RecordBuilder::<TestLabels>::new()
+            .set::<Label3>(true)
+            .set::<Label2>("foo".to_string())
+            .set::<Label1>(4)
+            .build();
+        assert_eq!(built, expected);
+
+        let partial = RecordBuilder::<TestLabels>::default().set::<Label2>("foo".to_string());
+        let built = partial.set::<Label3>(true).set::<Label1>(4).build();
+        assert_eq!(built, expected);
+
+        assert_eq!(RecordBuilder::<Nil>::new().build(), Nil);
+    }
+}
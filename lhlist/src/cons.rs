@@ -1,10 +1,20 @@
 use std::marker::PhantomData;
-use std::ops::{Index, IndexMut};
+use std::ops::{Add, Index, IndexMut};
 
+use typenum::{Add1, Unsigned, B1, U0};
+
+use crate::defaults::{FillDefaults, LabelDefault};
 use crate::iter::{ConsIterator, ValuesIterator};
-use crate::label::{LabeledValue, Value};
-use crate::lookup::{LookupElemByLabel, LookupElemByLabelMut};
+use crate::label::{Label, LabeledValue, Value};
+use crate::lookup::{
+    At, IndexOf, LookupElemByLabel, LookupElemByLabelMut, LookupValueByType, LookupValueByTypeMut,
+    Pluck,
+};
+use crate::nested::{Flatten, Path, PathMut};
 use crate::relation::{Bool, Member};
+use crate::reverse::{Init, Last, Reverse};
+use crate::split::{SplitAt, SplitAtLabel};
+use crate::transform::{Relabel, Replace};
 
 /// The end of a heterogeneous list.
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
@@ -70,6 +80,19 @@ where
     }
 }
 
+impl<L, Tail> Default for LVCons<L, Tail>
+where
+    L: LabelDefault,
+    Tail: Default,
+{
+    fn default() -> LVCons<L, Tail> {
+        Cons {
+            head: LabeledValue::default(),
+            tail: Tail::default(),
+        }
+    }
+}
+
 impl<Head, Tail> Cons<Head, Tail> {
     /// Returns a iterator over this cons-list.
     pub fn iter<'a>(&'a self) -> ConsIterator<'a, Self> {
@@ -260,6 +283,539 @@ impl<Head, Tail> Cons<Head, Tail> {
     {
         LookupElemByLabelMut::<TargetL>::elem_mut(self).value_mut()
     }
+
+    /// Returns a reference to the unique value in this list of type `T`.
+    ///
+    /// The second type parameter is a type-level index which is inferred by the compiler, and can
+    /// be left as `_`. This method fails to compile if there is no value of type `T` in the list, or
+    /// if there is more than one.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate lhlist;
+    /// # fn main() {
+    /// new_label![Label1: u8];
+    /// new_label![Label2: String];
+    /// new_label![Label3: bool];
+    /// let list = lhlist![
+    ///     Label1 = 9,
+    ///     Label2 = "foo".to_string(),
+    ///     Label3 = true,
+    /// ];
+    /// assert_eq!(list.get_by_type::<String, _>(), "foo");
+    /// assert_eq!(list.get_by_type::<bool, _>(), &true);
+    /// # }
+    /// ```
+    ///
+    /// Lookup fails to compile when the type is ambiguous:
+    ///
+    /// ```compile_fail
+    /// # #[macro_use] extern crate lhlist;
+    /// # fn main() {
+    /// new_label![Label1: u8];
+    /// new_label![Label2: u8];
+    /// let list = lhlist![Label1 = 9, Label2 = 4];
+    /// list.get_by_type::<u8, _>();
+    /// # }
+    /// ```
+    pub fn get_by_type<T, Index>(&self) -> &T
+    where
+        Self: LookupValueByType<T, Index>,
+    {
+        LookupValueByType::<T, Index>::value_by_type(self)
+    }
+
+    /// Returns a mutable reference to the unique value in this list of type `T`.
+    ///
+    /// See [get_by_type](struct.Cons.html#method.get_by_type) for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate lhlist;
+    /// # fn main() {
+    /// new_label![Label1: u8];
+    /// new_label![Label2: String];
+    /// let mut list = lhlist![Label1 = 9, Label2 = "foo".to_string()];
+    /// list.get_by_type_mut::<String, _>().push_str("bar");
+    /// assert_eq!(list, lhlist![Label1 = 9, Label2 = "foobar".to_string()]);
+    /// # }
+    /// ```
+    pub fn get_by_type_mut<T, Index>(&mut self) -> &mut T
+    where
+        Self: LookupValueByTypeMut<T, Index>,
+    {
+        LookupValueByTypeMut::<T, Index>::value_by_type_mut(self)
+    }
+
+    /// Returns a reference to the element at a specific position in this list.
+    ///
+    /// The position `N` is a [typenum](https://docs.rs/typenum) unsigned integer. To find the
+    /// position of a specific label, see [IndexOf](trait.IndexOf.html).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate lhlist;
+    /// use typenum::{U0, U2};
+    /// # fn main() {
+    /// let list = cons![8, "Hello!", 4.5];
+    /// assert_eq!(list.at::<U0>(), &8);
+    /// assert_eq!(list.at::<U2>(), &4.5);
+    /// # }
+    /// ```
+    pub fn at<N>(&self) -> &<Self as At<N>>::Output
+    where
+        Self: At<N>,
+    {
+        At::<N>::at(self)
+    }
+
+    /// Returns a mutable reference to the element at a specific position in this list.
+    ///
+    /// See [at](struct.Cons.html#method.at) for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate lhlist;
+    /// use typenum::U1;
+    /// # fn main() {
+    /// let mut list = cons![8, "Hello!", 4.5];
+    /// *list.at_mut::<U1>() = "World!";
+    /// assert_eq!(list, cons![8, "World!", 4.5]);
+    /// # }
+    /// ```
+    pub fn at_mut<N>(&mut self) -> &mut <Self as At<N>>::Output
+    where
+        Self: At<N>,
+    {
+        At::<N>::at_mut(self)
+    }
+
+    /// Returns a list in which the values of [nested](trait.LabelNested.html) labels are spliced
+    /// into this list in place of their elements. Flattening is recursive.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate lhlist;
+    /// # fn main() {
+    /// new_label![Name: &'static str];
+    /// new_label![City: &'static str];
+    /// new_label![Zip: u32];
+    /// #[label(type=LHList![City, Zip], nested)]
+    /// struct Address;
+    ///
+    /// let customer = lhlist![Name = "Jane", Address = lhlist![City = "Springfield", Zip = 12345]];
+    /// assert_eq!(
+    ///     customer.flatten(),
+    ///     lhlist![Name = "Jane", City = "Springfield", Zip = 12345]
+    /// );
+    /// # }
+    /// ```
+    pub fn flatten(self) -> <Self as Flatten>::Output
+    where
+        Self: Flatten,
+    {
+        Flatten::flatten(self)
+    }
+
+    /// Returns a reference to the value at the end of a path of labels through nested label-value
+    /// cons-lists. The path `P` is a tuple of labels.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate lhlist;
+    /// # fn main() {
+    /// new_label![City: &'static str];
+    /// new_label![Address: LHList![City]];
+    /// new_label![Customer: LHList![Address]];
+    ///
+    /// let order = lhlist![Customer = lhlist![Address = lhlist![City = "Springfield"]]];
+    /// assert_eq!(order.path::<(Customer, Address, City)>(), &"Springfield");
+    /// # }
+    /// ```
+    pub fn path<'a, P>(&'a self) -> &'a <Self as Path<P>>::Output
+    where
+        Self: Path<P>,
+        P: 'a,
+    {
+        Path::<P>::path(self)
+    }
+
+    /// Returns a mutable reference to the value at the end of a path of labels through nested
+    /// label-value cons-lists.
+    ///
+    /// See [path](struct.Cons.html#method.path) for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate lhlist;
+    /// # fn main() {
+    /// new_label![City: &'static str];
+    /// new_label![Address: LHList![City]];
+    ///
+    /// let mut customer = lhlist![Address = lhlist![City = "Springfield"]];
+    /// *customer.path_mut::<(Address, City)>() = "Shelbyville";
+    /// assert_eq!(customer, lhlist![Address = lhlist![City = "Shelbyville"]]);
+    /// # }
+    /// ```
+    pub fn path_mut<'a, P>(&'a mut self) -> &'a mut <Self as Path<P>>::Output
+    where
+        Self: PathMut<P>,
+        P: 'a,
+    {
+        PathMut::<P>::path_mut(self)
+    }
+
+    /// Returns a list with the elements of this list in reverse order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate lhlist;
+    /// # fn main() {
+    /// let list = cons![8, "Hello!", 4.5];
+    /// assert_eq!(list.reverse(), cons![4.5, "Hello!", 8]);
+    /// # }
+    /// ```
+    pub fn reverse(self) -> <Self as Reverse>::Output
+    where
+        Self: Reverse,
+    {
+        Reverse::reverse(self)
+    }
+
+    /// Returns a reference to the last element of this list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate lhlist;
+    /// # fn main() {
+    /// new_label![Label1: u8];
+    /// new_label![Label2: bool];
+    /// let list = lhlist![Label1 = 9, Label2 = true];
+    /// assert!(list.last().value);
+    /// # }
+    /// ```
+    pub fn last(&self) -> &<Self as Last>::Output
+    where
+        Self: Last,
+    {
+        Last::last(self)
+    }
+
+    /// Returns a mutable reference to the last element of this list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate lhlist;
+    /// # fn main() {
+    /// let mut list = cons![8, "Hello!", 4.5];
+    /// *list.last_mut() = 2.0;
+    /// assert_eq!(list, cons![8, "Hello!", 2.0]);
+    /// # }
+    /// ```
+    pub fn last_mut(&mut self) -> &mut <Self as Last>::Output
+    where
+        Self: Last,
+    {
+        Last::last_mut(self)
+    }
+
+    /// Returns a list of all elements of this list except the last.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate lhlist;
+    /// # fn main() {
+    /// let list = cons![8, "Hello!", 4.5];
+    /// assert_eq!(list.init(), cons![8, "Hello!"]);
+    /// # }
+    /// ```
+    pub fn init(self) -> <Self as Init>::Output
+    where
+        Self: Init,
+    {
+        Init::init(self)
+    }
+
+    /// Splits this list into two lists at a specific position, returning the first `N` elements
+    /// and the remaining elements.
+    ///
+    /// The position `N` is a [typenum](https://docs.rs/typenum) unsigned integer, and must not be
+    /// larger than the length of this list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate lhlist;
+    /// use typenum::U1;
+    /// # fn main() {
+    /// let list = cons![8, "Hello!", 4.5];
+    /// assert_eq!(list.split_at::<U1>(), (cons![8], cons!["Hello!", 4.5]));
+    /// # }
+    /// ```
+    pub fn split_at<N>(self) -> (<Self as SplitAt<N>>::Left, <Self as SplitAt<N>>::Right)
+    where
+        Self: SplitAt<N>,
+    {
+        SplitAt::<N>::split_at(self)
+    }
+
+    /// Returns a list of the first `N` elements of this list.
+    ///
+    /// See [split_at](struct.Cons.html#method.split_at) for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate lhlist;
+    /// use typenum::U2;
+    /// # fn main() {
+    /// let list = cons![8, "Hello!", 4.5];
+    /// assert_eq!(list.take::<U2>(), cons![8, "Hello!"]);
+    /// # }
+    /// ```
+    pub fn take<N>(self) -> <Self as SplitAt<N>>::Left
+    where
+        Self: SplitAt<N>,
+    {
+        SplitAt::<N>::split_at(self).0
+    }
+
+    /// Returns a list of the elements of this list after the first `N` elements.
+    ///
+    /// See [split_at](struct.Cons.html#method.split_at) for more details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate lhlist;
+    /// use typenum::U2;
+    /// # fn main() {
+    /// let list = cons![8, "Hello!", 4.5];
+    /// assert_eq!(list.skip::<U2>(), cons![4.5]);
+    /// # }
+    /// ```
+    pub fn skip<N>(self) -> <Self as SplitAt<N>>::Right
+    where
+        Self: SplitAt<N>,
+    {
+        SplitAt::<N>::split_at(self).1
+    }
+
+    /// Splits this list into two lists at the position of a specific label. The first list contains
+    /// the elements before the element labeled by `TargetL`, and the second list starts with that
+    /// element.
+    ///
+    /// Fails to compile if this list doesn't contain `TargetL`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate lhlist;
+    /// # fn main() {
+    /// new_label![Id: u32];
+    /// new_label![Name: &'static str];
+    /// new_label![Age: u8];
+    ///
+    /// let list = lhlist![Id = 12, Name = "Jane", Age = 37];
+    /// let (key, payload) = list.split_at_label::<Name>();
+    /// assert_eq!(key, lhlist![Id = 12]);
+    /// assert_eq!(payload, lhlist![Name = "Jane", Age = 37]);
+    /// # }
+    /// ```
+    pub fn split_at_label<TargetL>(
+        self,
+    ) -> (
+        <Self as SplitAtLabel<TargetL>>::Left,
+        <Self as SplitAtLabel<TargetL>>::Right,
+    )
+    where
+        Self: SplitAtLabel<TargetL>,
+    {
+        SplitAtLabel::<TargetL>::split_at_label(self)
+    }
+
+    /// Returns the position of a label in this list.
+    ///
+    /// See [IndexOf](trait.IndexOf.html) for the type-level version of this position.
+    pub fn index_of<TargetL>(&self) -> usize
+    where
+        Self: IndexOf<TargetL>,
+    {
+        <Self as IndexOf<TargetL>>::Output::to_usize()
+    }
+
+    /// Removes the element labeled by a specific label from this list, returning the element along
+    /// with the remaining list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate lhlist;
+    /// use lhlist::labeled;
+    /// # fn main() {
+    /// new_label![Label1: u8];
+    /// new_label![Label2: i8];
+    /// new_label![Label3: bool];
+    /// let list = lhlist![
+    ///     Label1 = 9,
+    ///     Label2 = -4,
+    ///     Label3 = true,
+    /// ];
+    ///
+    /// let (elem, rest) = list.pluck::<Label2>();
+    /// assert_eq!(elem, labeled(Label2, -4));
+    /// assert_eq!(rest, lhlist![Label1 = 9, Label3 = true]);
+    /// # }
+    /// ```
+    pub fn pluck<TargetL>(
+        self,
+    ) -> (
+        <Self as Pluck<TargetL>>::Elem,
+        <Self as Pluck<TargetL>>::Remainder,
+    )
+    where
+        Self: Pluck<TargetL>,
+    {
+        Pluck::<TargetL>::pluck(self)
+    }
+
+    /// Replaces the element labeled by `Old` with an element labeled by `New`, whose value is
+    /// computed by applying `f` to the old value. All other elements are left in place.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate lhlist;
+    /// # fn main() {
+    /// new_label![Name: &'static str];
+    /// new_label![Scores: Vec<&'static str>];
+    /// new_label![ParsedScores: Vec<u32>];
+    ///
+    /// let list = lhlist![Name = "Jane", Scores = vec!["6", "8"]];
+    /// let list = list.replace::<Scores, ParsedScores>(|scores| {
+    ///     scores.iter().map(|score| score.parse().unwrap()).collect()
+    /// });
+    /// assert_eq!(list, lhlist![Name = "Jane", ParsedScores = vec![6, 8]]);
+    /// # }
+    /// ```
+    ///
+    /// Replacing fails to compile when `New` already labels another element:
+    ///
+    /// ```compile_fail
+    /// # #[macro_use] extern crate lhlist;
+    /// # fn main() {
+    /// new_label![Name: &'static str];
+    /// new_label![Scores: Vec<&'static str>];
+    /// new_label![ParsedScores: Vec<u32>];
+    ///
+    /// let list = lhlist![Name = "Jane", Scores = vec!["6", "8"], ParsedScores = vec![]];
+    /// let list = list.replace::<Scores, ParsedScores>(|scores| {
+    ///     scores.iter().map(|score| score.parse().unwrap()).collect()
+    /// });
+    /// # }
+    /// ```
+    pub fn replace<Old, New>(
+        self,
+        f: impl FnOnce(<Self as Replace<Old, New>>::Value) -> New::AssocType,
+    ) -> <Self as Replace<Old, New>>::Output
+    where
+        New: Label,
+        Self: Replace<Old, New>,
+    {
+        Replace::<Old, New>::replace(self, f)
+    }
+
+    /// Moves the values of this list into a new list with the labels in `Labels` (an
+    /// [LCons](type.LCons.html) label-only cons-list), in order.
+    ///
+    /// Fails to compile if `Labels` has a different length than this list, if the associated type
+    /// of a label in `Labels` doesn't match the type of the value it relabels, or if `Labels`
+    /// contains the same label more than once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate lhlist;
+    /// # fn main() {
+    /// new_label![Name: &'static str];
+    /// new_label![Age: u8];
+    /// new_label![Customer: &'static str];
+    /// new_label![CustomerAge: u8];
+    ///
+    /// let list = lhlist![Name = "Jane", Age = 37];
+    /// let list = list.relabel::<Labels![Customer, CustomerAge]>();
+    /// assert_eq!(list, lhlist![Customer = "Jane", CustomerAge = 37]);
+    /// # }
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # #[macro_use] extern crate lhlist;
+    /// # fn main() {
+    /// # new_label![Name: &'static str];
+    /// # new_label![Age: u8];
+    /// new_label![Customer: &'static str];
+    /// new_label![CustomerAge: u16];
+    ///
+    /// let list = lhlist![Name = "Jane", Age = 37];
+    /// let list = list.relabel::<Labels![Customer, CustomerAge]>();
+    /// # }
+    /// ```
+    ///
+    /// ```compile_fail
+    /// # #[macro_use] extern crate lhlist;
+    /// use lhlist::{LCons, Nil};
+    /// # fn main() {
+    /// # new_label![Name: &'static str];
+    /// # new_label![Nickname: &'static str];
+    /// new_label![Customer: &'static str];
+    ///
+    /// let list = lhlist![Name = "Jane", Nickname = "JJ"];
+    /// let list = list.relabel::<LCons<Customer, LCons<Customer, Nil>>>();
+    /// # }
+    /// ```
+    pub fn relabel<Labels>(self) -> <Self as Relabel<Labels>>::Output
+    where
+        Self: Relabel<Labels>,
+    {
+        Relabel::<Labels>::relabel(self)
+    }
+
+    /// Extends this list to the labels in `Labels` (an [LCons](type.LCons.html) label-only
+    /// cons-list), filling in the [default values](trait.LabelDefault.html) of any labels not in
+    /// this list. The resulting list is in the order of `Labels`.
+    ///
+    /// Fails to compile if this list contains a label which isn't in `Labels`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #[macro_use] extern crate lhlist;
+    /// # fn main() {
+    /// new_label![Host: &'static str = "localhost"];
+    /// new_label![Port: u16 = 8080];
+    /// new_label![Verbose: bool];
+    ///
+    /// let config = lhlist![Port = 3000].fill_defaults::<Labels![Host, Port, Verbose]>();
+    /// assert_eq!(config, lhlist![Host = "localhost", Port = 3000, Verbose = false]);
+    /// # }
+    /// ```
+    pub fn fill_defaults<Labels>(self) -> <Self as FillDefaults<Labels>>::Output
+    where
+        Self: FillDefaults<Labels>,
+    {
+        FillDefaults::<Labels>::fill_defaults(self)
+    }
 }
 
 impl Nil {
@@ -284,6 +840,24 @@ impl Nil {
     pub fn iter_values<'a>(&'a self) -> ValuesIterator<'a, Self> {
         ValuesIterator::new(self)
     }
+    /// Returns `Nil`, since the `Nil` list has no elements. See
+    /// [flatten](struct.Cons.html#method.flatten) for more details.
+    pub fn flatten(self) -> Nil {
+        Nil
+    }
+    /// Returns `Nil`, since the `Nil` list has no elements. See
+    /// [reverse](struct.Cons.html#method.reverse) for more details.
+    pub fn reverse(self) -> Nil {
+        Nil
+    }
+    /// Creates a list of the [default values](trait.LabelDefault.html) of the labels in `Labels`.
+    /// See [fill_defaults](struct.Cons.html#method.fill_defaults) for more details.
+    pub fn fill_defaults<Labels>(self) -> <Self as FillDefaults<Labels>>::Output
+    where
+        Self: FillDefaults<Labels>,
+    {
+        FillDefaults::<Labels>::fill_defaults(self)
+    }
 }
 
 impl<L, H, T> Index<L> for Cons<H, T>
@@ -310,7 +884,8 @@ where
 
 /// Provides the length of a cons-list.
 ///
-/// Since cons-list types are statically defined, this length is known at compile-time.
+/// Since cons-list types are statically defined, this length is known at compile-time. For the
+/// length as a type-level integer, see [LenSize](trait.LenSize.html).
 ///
 /// ## Example
 ///
@@ -324,6 +899,8 @@ where
 ///
 /// let list: MyList = cons![8, "Hello!", 4.5];
 /// assert_eq!(list.len(), 3usize);
+/// assert!(!list.is_empty());
+/// assert!(Nil.is_empty());
 /// # }
 /// ```
 pub trait Len {
@@ -334,6 +911,10 @@ pub trait Len {
     fn len(&self) -> usize {
         Self::LEN
     }
+    /// Returns `true` if this list has no elements
+    fn is_empty(&self) -> bool {
+        Self::LEN == 0
+    }
 }
 
 impl Len for Nil {
@@ -346,6 +927,79 @@ where
     const LEN: usize = 1 + <T as Len>::LEN;
 }
 
+/// Provides the length of a cons-list as a [typenum](https://docs.rs/typenum) unsigned integer, for
+/// use in type-level computations.
+///
+/// ## Example
+///
+/// ```
+/// # #[macro_use] extern crate lhlist;
+/// # fn main() {
+/// use lhlist::{Cons, LenSize, Nil};
+/// use typenum::{Unsigned, U3};
+///
+/// type MyList = Cons<usize, Cons<&'static str, Cons<f32, Nil>>>;
+/// assert_eq!(<MyList as LenSize>::Size::to_usize(), 3usize);
+///
+/// fn takes_three<L: LenSize<Size = U3>>(_list: &L) {}
+///
+/// let list: MyList = cons![8, "Hello!", 4.5];
+/// takes_three(&list);
+/// # }
+/// ```
+pub trait LenSize: Len {
+    /// The length of this list, as a type-level unsigned integer
+    type Size: Unsigned;
+}
+
+impl LenSize for Nil {
+    type Size = U0;
+}
+impl<H, T> LenSize for Cons<H, T>
+where
+    T: LenSize,
+    <T as LenSize>::Size: Add<B1>,
+    Add1<<T as LenSize>::Size>: Unsigned,
+{
+    type Size = Add1<<T as LenSize>::Size>;
+}
+
+/// Marker trait for two cons-lists of equal length.
+///
+/// This trait can be used in bounds of operations (such as zipping or transposing) which require
+/// lists of the same length.
+///
+/// ## Example
+///
+/// ```
+/// # #[macro_use] extern crate lhlist;
+/// # fn main() {
+/// use lhlist::SameLength;
+///
+/// fn same_length<A: SameLength<B>, B>(_a: &A, _b: &B) {}
+///
+/// same_length(&cons![1u8, "two", 3.0], &cons!['a', 'b', 'c']);
+/// # }
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate lhlist;
+/// # fn main() {
+/// use lhlist::SameLength;
+///
+/// fn same_length<A: SameLength<B>, B>(_a: &A, _b: &B) {}
+///
+/// same_length(&cons![1u8, "two", 3.0], &cons!['a', 'b']);
+/// # }
+/// ```
+pub trait SameLength<Other> {}
+
+impl SameLength<Nil> for Nil {}
+impl<H, T, OtherH, OtherT> SameLength<Cons<OtherH, OtherT>> for Cons<H, T> where
+    T: SameLength<OtherT>
+{
+}
+
 /// Macro for creation of a [Cons](struct.Cons.html)-list.
 ///
 /// # Example
@@ -367,13 +1021,7 @@ where
 /// ```
 #[macro_export]
 macro_rules! cons {
-    () => ( $crate::Nil );
-    ($value:expr) => (
-        $crate::cons($value, $crate::Nil)
-    );
-    ($value:expr, $($rest:tt)*) => (
-        $crate::cons($value, cons![$($rest)*])
-    );
+    ($($tokens:tt)*) => ( $crate::__cons![$crate; $($tokens)*] );
 }
 
 #[cfg(test)]
@@ -407,4 +1055,41 @@ mod tests {
             }
         ];
     }
+
+    #[test]
+    fn cons_macro_large() {
+        let list = crate::cons![
+            0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23,
+            24, 25, 26, 27, 28, 29, 30, 31, 32, 33, 34, 35, 36, 37, 38, 39, 40, 41, 42, 43, 44, 45,
+            46, 47, 48, 49, 50, 51, 52, 53, 54, 55, 56, 57, 58, 59, 60, 61, 62, 63, 64, 65, 66, 67,
+            68, 69, 70, 71, 72, 73, 74, 75, 76, 77, 78, 79, 80, 81, 82, 83, 84, 85, 86, 87, 88, 89,
+            90, 91, 92, 93, 94, 95, 96, 97, 98, 99
+        ];
+        assert_eq!(list.head, 0);
+        assert_eq!(list.tail.tail.head, 2);
+        assert_eq!(list.len(), 100);
+    }
+
+    #[test]
+    fn len() {
+        use typenum::Unsigned;
+
+        let list = cons![8usize, "Hello!!!", 5.3];
+        assert_eq!(list.len(), 3);
+        assert!(!list.is_empty());
+        assert!(Nil.is_empty());
+        assert_eq!(
+            <Cons<u8, Cons<u16, Cons<u32, Nil>>> as LenSize>::Size::USIZE,
+            3
+        );
+
+        fn same_size<A, B>(a: &A, b: &B) -> usize
+        where
+            A: LenSize + SameLength<B>,
+            B: LenSize<Size = A::Size>,
+        {
+            a.len() + b.len()
+        }
+        assert_eq!(same_size(&list, &cons!['a', 'b', 'c']), 6);
+    }
 }
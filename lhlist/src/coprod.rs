@@ -0,0 +1,426 @@
+use crate::iter::MapFunc;
+use crate::label::{Label, LabeledValue};
+use crate::relation::{False, LabelEq, Member, Or, True};
+
+/// Main building block of a heterogeneous coproduct (tagged sum).
+///
+/// A coproduct holds exactly one value, whose type is one of a list of types. Similar to a
+/// [Cons](struct.Cons.html)-list, a coproduct is built from nested variants: `Inl` holds a value
+/// of the first type, while `Inr` holds a coproduct of the remaining types. The end of the list of
+/// types is marked by the uninhabited [CNil](enum.CNil.html).
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Coprod<H, T> {
+    /// The value is of the first type in the coproduct.
+    Inl(H),
+    /// The value is of one of the remaining types in the coproduct.
+    Inr(T),
+}
+
+/// The end of a heterogeneous coproduct.
+///
+/// `CNil` is uninhabited, and can never be instantiated.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum CNil {}
+
+/// A coproduct containing one of a set of labeled values.
+///
+/// Exactly one of the labeled variants is inhabited. The type of a labeled coproduct is typically
+/// written using the [Coprod](macro.Coprod.html) macro.
+///
+/// ## Example
+/// ```
+/// # #[macro_use] extern crate lhlist;
+/// use lhlist::iter::MapFunc;
+/// # fn main() {
+/// new_label![Count: usize];
+/// new_label![Message: String];
+///
+/// type Event = Coprod![Count, Message];
+///
+/// let event = Event::inject::<Message>("hello".to_string());
+/// assert_eq!(event.get::<Message>(), Some(&"hello".to_string()));
+/// assert_eq!(event.get::<Count>(), None);
+///
+/// struct Describe;
+/// impl MapFunc<usize> for Describe {
+///     type Output = String;
+///     fn call(&mut self, count: usize) -> String {
+///         format!("{} items", count)
+///     }
+/// }
+/// impl MapFunc<String> for Describe {
+///     type Output = String;
+///     fn call(&mut self, message: String) -> String {
+///         message
+///     }
+/// }
+/// assert_eq!(Event::inject::<Count>(3).fold(Describe), "3 items");
+/// assert_eq!(event.clone().fold(Describe), "hello");
+///
+/// // removing a variant
+/// match event.uninject::<Count>() {
+///     Ok(count) => panic!("unexpected count: {}", count),
+///     Err(rest) => assert_eq!(rest.get::<Message>(), Some(&"hello".to_string())),
+/// }
+/// # }
+/// ```
+pub type LVCoprod<Label, Tail> = Coprod<LabeledValue<Label>, Tail>;
+
+/// Macro for creating type signature for a [LVCoprod](type.LVCoprod.html) labeled coproduct.
+///
+/// `Coprod![A, B]` is equivalent to `LVCoprod<A, LVCoprod<B, CNil>>`.
+#[macro_export]
+macro_rules! Coprod {
+    ($($tokens:tt)*) => ( $crate::__Coprod![$crate; $($tokens)*] );
+}
+
+impl<H, T> Coprod<H, T> {
+    /// Creates a new coproduct containing a value for the variant labeled by a specific label.
+    pub fn inject<TargetL>(value: <Self as CoprodInjector<TargetL>>::Value) -> Self
+    where
+        Self: CoprodInjector<TargetL>,
+    {
+        CoprodInjector::<TargetL>::inject(value)
+    }
+
+    /// Returns a reference to the contained value if the variant labeled by a specific label is
+    /// inhabited, and `None` otherwise.
+    pub fn get<TargetL>(&self) -> Option<&<Self as CoprodSelector<TargetL>>::Value>
+    where
+        Self: CoprodSelector<TargetL>,
+    {
+        CoprodSelector::<TargetL>::get(self)
+    }
+
+    /// Attempts to extract the value of the variant labeled by a specific label.
+    ///
+    /// Returns the value if that variant is inhabited, or otherwise a coproduct of the remaining
+    /// variants.
+    #[allow(clippy::type_complexity)]
+    pub fn uninject<TargetL>(
+        self,
+    ) -> Result<
+        <Self as CoprodUninjector<TargetL>>::Value,
+        <Self as CoprodUninjector<TargetL>>::Remainder,
+    >
+    where
+        Self: CoprodUninjector<TargetL>,
+    {
+        CoprodUninjector::<TargetL>::uninject(self)
+    }
+
+    /// Consumes this coproduct, calling a [MapFunc](iter/trait.MapFunc.html) on the contained
+    /// value.
+    ///
+    /// The function must be implemented for the value types of every variant, with the same output
+    /// type.
+    pub fn fold<F, Output>(self, f: F) -> Output
+    where
+        Self: CoprodFold<F, Output>,
+    {
+        let mut f = f;
+        CoprodFold::fold(self, &mut f)
+    }
+}
+
+impl<TargetL> Member<TargetL> for CNil {
+    type Output = False;
+}
+impl<TargetL, L, T> Member<TargetL> for Coprod<L, T>
+where
+    L: Label + LabelEq<TargetL>,
+    T: Member<TargetL>,
+    <L as LabelEq<TargetL>>::Output: Or<<T as Member<TargetL>>::Output>,
+{
+    type Output = <<L as LabelEq<TargetL>>::Output as Or<<T as Member<TargetL>>::Output>>::Output;
+}
+
+/// Creates a coproduct containing a value for the variant labeled by a specific label.
+pub trait CoprodInjector<TargetL> {
+    /// The type of the injected value
+    type Value;
+    /// Creates the coproduct
+    fn inject(value: Self::Value) -> Self;
+}
+
+impl<TargetL, L, T> CoprodInjector<TargetL> for LVCoprod<L, T>
+where
+    L: Label + LabelEq<TargetL>,
+    Self: Member<TargetL, Output = True>,
+    Self: CoprodInjectorMatch<TargetL, <L as LabelEq<TargetL>>::Output>,
+{
+    type Value = <Self as CoprodInjectorMatch<TargetL, <L as LabelEq<TargetL>>::Output>>::Value;
+
+    fn inject(value: Self::Value) -> Self {
+        CoprodInjectorMatch::<TargetL, <L as LabelEq<TargetL>>::Output>::inject(value)
+    }
+}
+
+/// Helper trait for [CoprodInjector](trait.CoprodInjector.html).
+pub trait CoprodInjectorMatch<TargetL, HeadMatch> {
+    /// The type of the injected value
+    type Value;
+    /// Creates the coproduct
+    fn inject(value: Self::Value) -> Self;
+}
+
+// head matches
+impl<TargetL, L, T> CoprodInjectorMatch<TargetL, True> for LVCoprod<L, T>
+where
+    L: Label,
+{
+    type Value = L::AssocType;
+
+    fn inject(value: Self::Value) -> Self {
+        Coprod::Inl(LabeledValue::new(value))
+    }
+}
+
+// head doesn't match, inject into tail
+impl<TargetL, L, T> CoprodInjectorMatch<TargetL, False> for LVCoprod<L, T>
+where
+    L: Label,
+    T: CoprodInjector<TargetL>,
+{
+    type Value = <T as CoprodInjector<TargetL>>::Value;
+
+    fn inject(value: Self::Value) -> Self {
+        Coprod::Inr(T::inject(value))
+    }
+}
+
+/// Accesses the value of the variant labeled by a specific label, if inhabited.
+pub trait CoprodSelector<TargetL> {
+    /// The type of the selected value
+    type Value;
+    /// Returns a reference to the value if the variant is inhabited
+    fn get(&self) -> Option<&Self::Value>;
+}
+
+impl<TargetL, L, T> CoprodSelector<TargetL> for LVCoprod<L, T>
+where
+    L: Label + LabelEq<TargetL>,
+    Self: Member<TargetL, Output = True>,
+    Self: CoprodSelectorMatch<TargetL, <L as LabelEq<TargetL>>::Output>,
+{
+    type Value = <Self as CoprodSelectorMatch<TargetL, <L as LabelEq<TargetL>>::Output>>::Value;
+
+    fn get(&self) -> Option<&Self::Value> {
+        CoprodSelectorMatch::<TargetL, <L as LabelEq<TargetL>>::Output>::get(self)
+    }
+}
+
+/// Helper trait for [CoprodSelector](trait.CoprodSelector.html).
+pub trait CoprodSelectorMatch<TargetL, HeadMatch> {
+    /// The type of the selected value
+    type Value;
+    /// Returns a reference to the value if the variant is inhabited
+    fn get(&self) -> Option<&Self::Value>;
+}
+
+// head matches
+impl<TargetL, L, T> CoprodSelectorMatch<TargetL, True> for LVCoprod<L, T>
+where
+    L: Label,
+{
+    type Value = L::AssocType;
+
+    fn get(&self) -> Option<&Self::Value> {
+        match self {
+            Coprod::Inl(head) => Some(&head.value),
+            Coprod::Inr(_) => None,
+        }
+    }
+}
+
+// head doesn't match, look in tail
+impl<TargetL, L, T> CoprodSelectorMatch<TargetL, False> for LVCoprod<L, T>
+where
+    L: Label,
+    T: CoprodSelector<TargetL>,
+{
+    type Value = <T as CoprodSelector<TargetL>>::Value;
+
+    fn get(&self) -> Option<&Self::Value> {
+        match self {
+            Coprod::Inl(_) => None,
+            Coprod::Inr(tail) => tail.get(),
+        }
+    }
+}
+
+/// Extracts the value of the variant labeled by a specific label, if inhabited.
+pub trait CoprodUninjector<TargetL> {
+    /// The type of the extracted value
+    type Value;
+    /// The coproduct of the remaining variants
+    type Remainder;
+    /// Returns the value if the variant is inhabited, or the coproduct of the remaining variants
+    fn uninject(self) -> Result<Self::Value, Self::Remainder>;
+}
+
+impl<TargetL, L, T> CoprodUninjector<TargetL> for LVCoprod<L, T>
+where
+    L: Label + LabelEq<TargetL>,
+    Self: Member<TargetL, Output = True>,
+    Self: CoprodUninjectorMatch<TargetL, <L as LabelEq<TargetL>>::Output>,
+{
+    type Value = <Self as CoprodUninjectorMatch<TargetL, <L as LabelEq<TargetL>>::Output>>::Value;
+    type Remainder =
+        <Self as CoprodUninjectorMatch<TargetL, <L as LabelEq<TargetL>>::Output>>::Remainder;
+
+    fn uninject(self) -> Result<Self::Value, Self::Remainder> {
+        CoprodUninjectorMatch::<TargetL, <L as LabelEq<TargetL>>::Output>::uninject(self)
+    }
+}
+
+/// Helper trait for [CoprodUninjector](trait.CoprodUninjector.html).
+pub trait CoprodUninjectorMatch<TargetL, HeadMatch> {
+    /// The type of the extracted value
+    type Value;
+    /// The coproduct of the remaining variants
+    type Remainder;
+    /// Returns the value if the variant is inhabited, or the coproduct of the remaining variants
+    fn uninject(self) -> Result<Self::Value, Self::Remainder>;
+}
+
+// head matches
+impl<TargetL, L, T> CoprodUninjectorMatch<TargetL, True> for LVCoprod<L, T>
+where
+    L: Label,
+{
+    type Value = L::AssocType;
+    type Remainder = T;
+
+    fn uninject(self) -> Result<Self::Value, Self::Remainder> {
+        match self {
+            Coprod::Inl(head) => Ok(head.value),
+            Coprod::Inr(tail) => Err(tail),
+        }
+    }
+}
+
+// head doesn't match, look in tail
+impl<TargetL, L, T> CoprodUninjectorMatch<TargetL, False> for LVCoprod<L, T>
+where
+    L: Label,
+    T: CoprodUninjector<TargetL>,
+{
+    type Value = <T as CoprodUninjector<TargetL>>::Value;
+    type Remainder = LVCoprod<L, <T as CoprodUninjector<TargetL>>::Remainder>;
+
+    fn uninject(self) -> Result<Self::Value, Self::Remainder> {
+        match self {
+            Coprod::Inl(head) => Err(Coprod::Inl(head)),
+            Coprod::Inr(tail) => tail.uninject().map_err(Coprod::Inr),
+        }
+    }
+}
+
+/// Consumes a coproduct by calling a [MapFunc](iter/trait.MapFunc.html) on the contained value.
+pub trait CoprodFold<F, Output> {
+    /// Calls `f` on the contained value
+    fn fold(self, f: &mut F) -> Output;
+}
+
+impl<F, Output> CoprodFold<F, Output> for CNil {
+    fn fold(self, _f: &mut F) -> Output {
+        match self {}
+    }
+}
+
+impl<F, Output, L, T> CoprodFold<F, Output> for LVCoprod<L, T>
+where
+    L: Label,
+    F: MapFunc<L::AssocType, Output = Output>,
+    T: CoprodFold<F, Output>,
+{
+    fn fold(self, f: &mut F) -> Output {
+        match self {
+            Coprod::Inl(head) => f.call(head.value),
+            Coprod::Inr(tail) => tail.fold(f),
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::assertions_on_constants)]
+mod tests {
+    use crate::iter::MapFunc;
+    use crate::*;
+
+    #[label(type=u8, crate=crate)]
+    struct Label1;
+    #[label(type=String, crate=crate)]
+    struct Label2;
+    #[label(type=bool, crate=crate)]
+    struct Label3;
+
+    type TestCoprod = Coprod![Label1, Label2, Label3];
+
+    #[test]
+    fn inject_get() {
+        let first = TestCoprod::inject::<Label1>(4);
+        assert_eq!(first, Coprod::Inl(labeled(Label1, 4)));
+        assert_eq!(first.get::<Label1>(), Some(&4));
+        assert_eq!(first.get::<Label2>(), None);
+        assert_eq!(first.get::<Label3>(), None);
+
+        let third = TestCoprod::inject::<Label3>(true);
+        assert_eq!(
+            third,
+            Coprod::Inr(Coprod::Inr(Coprod::Inl(labeled(Label3, true))))
+        );
+        assert_eq!(third.get::<Label1>(), None);
+        assert_eq!(third.get::<Label3>(), Some(&true));
+    }
+
+    #[test]
+    fn uninject() {
+        let second = TestCoprod::inject::<Label2>("foo".to_string());
+        let rest = second.clone().uninject::<Label1>().unwrap_err();
+        assert_eq!(rest.get::<Label2>(), Some(&"foo".to_string()));
+        let rest = rest.uninject::<Label3>().unwrap_err();
+        assert_eq!(rest.uninject::<Label2>(), Ok("foo".to_string()));
+        assert_eq!(second.uninject::<Label2>(), Ok("foo".to_string()));
+    }
+
+    #[test]
+    fn fold() {
+        struct Describe;
+        impl MapFunc<u8> for Describe {
+            type Output = String;
+            fn call(&mut self, value: u8) -> String {
+                format!("u8: {}", value)
+            }
+        }
+        impl MapFunc<String> for Describe {
+            type Output = String;
+            fn call(&mut self, value: String) -> String {
+                format!("string: {}", value)
+            }
+        }
+        impl MapFunc<bool> for Describe {
+            type Output = String;
+            fn call(&mut self, value: bool) -> String {
+                format!("bool: {}", value)
+            }
+        }
+
+        assert_eq!(TestCoprod::inject::<Label1>(4).fold(Describe), "u8: 4");
+        assert_eq!(
+            TestCoprod::inject::<Label2>("foo".to_string()).fold(Describe),
+            "string: foo"
+        );
+        assert_eq!(
+            TestCoprod::inject::<Label3>(false).fold(Describe),
+            "bool: false"
+        );
+    }
+
+    #[test]
+    fn member() {
+        assert!(<TestCoprod as Member<Label2>>::Output::VALUE);
+        assert!(!<Coprod![Label1, Label3] as Member<Label2>>::Output::VALUE);
+    }
+}
@@ -0,0 +1,221 @@
+use crate::cons::{Cons, LCons, LVCons, Nil};
+use crate::label::{Label, LabeledValue};
+use crate::lookup::Pluck;
+use crate::relation::{Bool, False, Member, True};
+
+/// Trait for labels which provide a default value.
+///
+/// Labels created with the [label](attr.label.html) attribute or [new_label](macro.new_label.html)
+/// macro can specify a default value with the `default` option. Labels without a `default` option
+/// use the `Default` implementation of their associated type, if it exists.
+///
+/// ## Example
+/// ```
+/// # #[macro_use] extern crate lhlist;
+/// use lhlist::LabelDefault;
+/// # fn main() {
+/// new_label![Host: &'static str = "localhost"];
+/// new_label![Port: u16 = 8080];
+/// new_label![Verbose: bool];
+///
+/// assert_eq!(Host::default_value(), "localhost");
+/// assert_eq!(Port::default_value(), 8080);
+/// assert_eq!(Verbose::default_value(), false);
+///
+/// let config: LHList![Host, Port, Verbose] = Default::default();
+/// assert_eq!(config, lhlist![Host = "localhost", Port = 8080, Verbose = false]);
+/// # }
+/// ```
+///
+/// Labels whose associated types don't implement `Default` can still be declared and used, but
+/// have no default value:
+/// ```
+/// # #[macro_use] extern crate lhlist;
+/// use lhlist::StrLabels;
+/// # fn main() {
+/// struct Connection;
+/// new_label![Conn: Connection];
+///
+/// let list = lhlist![Conn = Connection];
+/// assert_eq!(list.labels(), vec!["Conn"]);
+/// # }
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate lhlist;
+/// use lhlist::LabelDefault;
+/// # fn main() {
+/// struct Connection;
+/// new_label![Conn: Connection];
+///
+/// let conn = Conn::default_value();
+/// # }
+/// ```
+pub trait LabelDefault: Label {
+    /// Returns the default value for this label
+    fn default_value() -> Self::AssocType;
+}
+
+/// Specifies where a label's [default value](trait.LabelDefault.html) comes from. Implemented by
+/// labels created with the [label](attr.label.html) attribute.
+///
+/// Labels with an explicit default value (`Explicit` is [True](struct.True.html)) provide it by
+/// implementing [`LabelDefaultMatch<True>`](trait.LabelDefaultMatch.html), while other labels use
+/// the `Default` implementation of their associated type, if it exists.
+pub trait LabelDefaultSource: Label {
+    /// Whether this label has an explicit default value
+    type Explicit: Bool;
+}
+
+/// Helper trait for [LabelDefault](trait.LabelDefault.html).
+pub trait LabelDefaultMatch<Explicit>: Label {
+    /// Returns the default value for this label
+    fn match_default_value() -> Self::AssocType;
+}
+
+impl<L> LabelDefault for L
+where
+    L: LabelDefaultSource + LabelDefaultMatch<<L as LabelDefaultSource>::Explicit>,
+{
+    fn default_value() -> L::AssocType {
+        <L as LabelDefaultMatch<L::Explicit>>::match_default_value()
+    }
+}
+
+// no explicit default value, use the associated type's `Default` implementation
+impl<L> LabelDefaultMatch<False> for L
+where
+    L: Label,
+    L::AssocType: Default,
+{
+    fn match_default_value() -> L::AssocType {
+        L::AssocType::default()
+    }
+}
+
+impl<L> Default for LabeledValue<L>
+where
+    L: LabelDefault,
+{
+    fn default() -> LabeledValue<L> {
+        LabeledValue::new(L::default_value())
+    }
+}
+
+/// Extends a label-value cons-list to the labels in `Labels` (an [LCons](type.LCons.html)
+/// label-only cons-list), using the [default values](trait.LabelDefault.html) of any labels missing
+/// from the list.
+///
+/// Every label in the list must be in `Labels`. The resulting list is in the order of `Labels`.
+pub trait FillDefaults<Labels> {
+    /// The resulting label-value cons-list
+    type Output;
+    /// Extends the list
+    fn fill_defaults(self) -> Self::Output;
+}
+
+impl FillDefaults<Nil> for Nil {
+    type Output = Nil;
+
+    fn fill_defaults(self) -> Nil {
+        Nil
+    }
+}
+
+impl<List, L, T> FillDefaults<LCons<L, T>> for List
+where
+    List: Member<L>,
+    List: FillDefaultsMatch<L, T, <List as Member<L>>::Output>,
+{
+    type Output = <List as FillDefaultsMatch<L, T, <List as Member<L>>::Output>>::Output;
+
+    fn fill_defaults(self) -> Self::Output {
+        FillDefaultsMatch::<L, T, <List as Member<L>>::Output>::fill_defaults(self)
+    }
+}
+
+/// Helper trait for [FillDefaults](trait.FillDefaults.html).
+pub trait FillDefaultsMatch<L, T, IsMember> {
+    /// The resulting label-value cons-list
+    type Output;
+    /// Extends the list
+    fn fill_defaults(self) -> Self::Output;
+}
+
+// label is in list, move value into output
+impl<List, L, T> FillDefaultsMatch<L, T, True> for List
+where
+    List: Pluck<L>,
+    <List as Pluck<L>>::Remainder: FillDefaults<T>,
+{
+    type Output =
+        Cons<<List as Pluck<L>>::Elem, <<List as Pluck<L>>::Remainder as FillDefaults<T>>::Output>;
+
+    fn fill_defaults(self) -> Self::Output {
+        let (head, remainder) = self.pluck();
+        Cons {
+            head,
+            tail: FillDefaults::<T>::fill_defaults(remainder),
+        }
+    }
+}
+
+// label isn't in list, use default value
+impl<List, L, T> FillDefaultsMatch<L, T, False> for List
+where
+    L: LabelDefault,
+    List: FillDefaults<T>,
+{
+    type Output = LVCons<L, <List as FillDefaults<T>>::Output>;
+
+    fn fill_defaults(self) -> Self::Output {
+        Cons {
+            head: LabeledValue::default(),
+            tail: FillDefaults::<T>::fill_defaults(self),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[label(type=u8, default=4, crate=crate)]
+    struct Label1;
+    #[label(type=String, crate=crate)]
+    struct Label2;
+    #[label(type=Vec<u32>, default=vec![1, 2], crate=crate)]
+    struct Label3;
+    struct NoDefault;
+    #[label(type=NoDefault, crate=crate)]
+    struct Label4;
+
+    #[test]
+    fn label_default() {
+        assert_eq!(Label1::default_value(), 4);
+        assert_eq!(Label2::default_value(), "");
+        assert_eq!(Label3::default_value(), vec![1, 2]);
+
+        let list: LHList![Label1, Label2, Label3] = Default::default();
+        assert_eq!(
+            list,
+            lhlist![Label1 = 4, Label2 = String::new(), Label3 = vec![1, 2]]
+        );
+        let _ = lhlist![Label4 = NoDefault];
+    }
+
+    #[test]
+    fn fill_defaults() {
+        type Full = Labels![Label1, Label2, Label3, Label4];
+        let list = lhlist![Label4 = NoDefault, Label2 = "foo".to_string()];
+        let list = list.fill_defaults::<Full>();
+        assert_eq!(list.labels(), vec!["Label1", "Label2", "Label3", "Label4"]);
+        assert_eq!(list[Label1], 4);
+        assert_eq!(list[Label2], "foo");
+        assert_eq!(list[Label3], vec![1, 2]);
+
+        let list = lhlist![Label3 = vec![]].fill_defaults::<Labels![Label3, Label1]>();
+        assert_eq!(list, lhlist![Label3 = vec![], Label1 = 4]);
+        assert_eq!(Nil.fill_defaults::<Labels![Label1]>(), lhlist![Label1 = 4]);
+    }
+}
@@ -0,0 +1,762 @@
+use std::any::{Any, TypeId};
+use std::convert::TryFrom;
+use std::error::Error;
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use crate::cons::{Cons, LVCons, Nil};
+use crate::label::{Label, LabeledValue};
+use crate::transpose::LabeledError;
+
+/// Runtime access to the values of a label-value cons-list by label name.
+///
+/// Names are compared to each label's [NAME](trait.Label.html#associatedconstant.NAME). If more
+/// than one label in the list has the same name, the first one is used.
+///
+/// ## Example
+/// ```
+/// # #[macro_use] extern crate lhlist;
+/// use lhlist::DynLookup;
+/// # fn main() {
+/// new_label![Name: String];
+/// new_label![Age: u8];
+///
+/// let mut list = lhlist![Name = "Jane".to_string(), Age = 37];
+/// assert_eq!(list.get_dyn("Age").and_then(|age| age.downcast_ref::<u8>()), Some(&37));
+/// assert!(list.get_dyn("Email").is_none());
+///
+/// if let Some(name) = list.get_dyn_mut("Name").and_then(|name| name.downcast_mut::<String>()) {
+///     name.push_str(" Doe");
+/// }
+/// assert_eq!(list[Name], "Jane Doe");
+/// # }
+/// ```
+pub trait DynLookup {
+    /// Returns a reference to the value of the label with the specified name, if it exists
+    fn get_dyn(&self, name: &str) -> Option<&dyn Any>;
+    /// Returns a mutable reference to the value of the label with the specified name, if it exists
+    fn get_dyn_mut(&mut self, name: &str) -> Option<&mut dyn Any>;
+}
+
+impl DynLookup for Nil {
+    fn get_dyn(&self, _name: &str) -> Option<&dyn Any> {
+        None
+    }
+    fn get_dyn_mut(&mut self, _name: &str) -> Option<&mut dyn Any> {
+        None
+    }
+}
+
+impl<L, T> DynLookup for LVCons<L, T>
+where
+    L: Label,
+    L::AssocType: Any,
+    T: DynLookup,
+{
+    fn get_dyn(&self, name: &str) -> Option<&dyn Any> {
+        if L::NAME == name {
+            Some(&self.head.value)
+        } else {
+            self.tail.get_dyn(name)
+        }
+    }
+    fn get_dyn_mut(&mut self, name: &str) -> Option<&mut dyn Any> {
+        if L::NAME == name {
+            Some(&mut self.head.value)
+        } else {
+            self.tail.get_dyn_mut(name)
+        }
+    }
+}
+
+/// Trait for labels whose values may be parsed from strings.
+///
+/// Labels created with the [label](attr.label.html) attribute parse their values using the
+/// `FromStr` implementation of their associated type (whose error type must implement `Display`).
+/// Labels with associated type `Option<T>` parse values into `Some` using the `FromStr`
+/// implementation of `T`.
+/// Labels whose associated types don't implement `FromStr` still implement this trait, but
+/// [parse_value](trait.LabelFromStr.html#tymethod.parse_value) always returns `None`.
+///
+/// ## Example
+/// ```
+/// # #[macro_use] extern crate lhlist;
+/// use lhlist::LabelFromStr;
+/// # fn main() {
+/// new_label![Port: u16];
+/// new_label![Tags: Vec<String>];
+///
+/// new_label![Timeout: Option<u32>];
+///
+/// assert_eq!(Port::parse_value("8080"), Some(Ok(8080)));
+/// assert_eq!(Timeout::parse_value("30"), Some(Ok(Some(30))));
+/// assert!(Port::parse_value("eighty").unwrap().is_err());
+/// assert_eq!(Tags::parse_value("a,b"), None);
+/// # }
+/// ```
+pub trait LabelFromStr: Label {
+    /// Parses a value for this label from `s`, returning an error message if parsing fails, or
+    /// `None` if values of this label can't be parsed from strings
+    fn parse_value(s: &str) -> Option<Result<Self::AssocType, String>>;
+}
+
+// Helpers for the `LabelFromStr` implementations generated by the `label` attribute, which select
+// between parsing with `FromStr` (directly, or into `Some` for `Option`s of parseable types) and
+// `None` using method resolution on the concrete associated type.
+#[doc(hidden)]
+pub struct __FromStrProbe<T>(pub PhantomData<T>);
+
+#[doc(hidden)]
+pub trait __ParseFromStr<T> {
+    fn parse_value(&self, s: &str) -> Option<Result<T, String>>;
+}
+impl<T> __ParseFromStr<T> for __FromStrProbe<T>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    fn parse_value(&self, s: &str) -> Option<Result<T, String>> {
+        Some(s.parse().map_err(|err: T::Err| err.to_string()))
+    }
+}
+
+#[doc(hidden)]
+pub trait __ParseOption<T> {
+    fn parse_value(&self, s: &str) -> Option<Result<Option<T>, String>>;
+}
+impl<T> __ParseOption<T> for __FromStrProbe<Option<T>>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    fn parse_value(&self, s: &str) -> Option<Result<Option<T>, String>> {
+        Some(s.parse().map(Some).map_err(|err: T::Err| err.to_string()))
+    }
+}
+
+#[doc(hidden)]
+pub trait __ParseUnsupported<T> {
+    fn parse_value(&self, s: &str) -> Option<Result<T, String>>;
+}
+impl<T> __ParseUnsupported<T> for &__FromStrProbe<T> {
+    fn parse_value(&self, _s: &str) -> Option<Result<T, String>> {
+        None
+    }
+}
+
+/// Error produced by [set_from_str](trait.SetFromStr.html#tymethod.set_from_str).
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub enum SetFromStrError {
+    /// No label in the list has the specified name
+    UnknownLabel(String),
+    /// The string could not be parsed into the label's associated type
+    Parse(LabeledError<String>),
+    /// The label's values can't be parsed from strings (see
+    /// [LabelFromStr](trait.LabelFromStr.html))
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for SetFromStrError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SetFromStrError::UnknownLabel(name) => write!(f, "unknown label: {}", name),
+            SetFromStrError::Parse(err) => write!(f, "parse error for {}", err),
+            SetFromStrError::Unsupported(label) => {
+                write!(f, "values of {} can't be parsed from strings", label)
+            }
+        }
+    }
+}
+
+impl Error for SetFromStrError {}
+
+/// Runtime mutation of the values of a label-value cons-list by label name, by parsing strings.
+///
+/// Values are parsed using each label's [LabelFromStr](trait.LabelFromStr.html) implementation;
+/// setting the value of a label whose values can't be parsed produces
+/// [Unsupported](enum.SetFromStrError.html#variant.Unsupported). Names are compared to each label's
+/// [NAME](trait.Label.html#associatedconstant.NAME). If more than one label in the list has the
+/// same name, the first one is used.
+///
+/// ## Example
+/// ```
+/// # #[macro_use] extern crate lhlist;
+/// use lhlist::{SetFromStr, SetFromStrError};
+/// # fn main() {
+/// new_label![Host: String];
+/// new_label![Port: u16];
+///
+/// let mut config = lhlist![Host = "localhost".to_string(), Port = 80];
+/// config.set_from_str("Port", "8080").unwrap();
+/// assert_eq!(config[Port], 8080);
+///
+/// match config.set_from_str("Timeout", "10") {
+///     Err(SetFromStrError::UnknownLabel(name)) => assert_eq!(name, "Timeout"),
+///     _ => panic!("expected unknown label"),
+/// }
+/// match config.set_from_str("Port", "eighty") {
+///     Err(SetFromStrError::Parse(err)) => assert_eq!(err.label, "Port"),
+///     _ => panic!("expected parse failure"),
+/// }
+/// assert_eq!(config[Port], 8080);
+/// # }
+/// ```
+pub trait SetFromStr {
+    /// Parses `value` and sets it as the value of the label with the specified name
+    fn set_from_str(&mut self, name: &str, value: &str) -> Result<(), SetFromStrError>;
+}
+
+impl SetFromStr for Nil {
+    fn set_from_str(&mut self, name: &str, _value: &str) -> Result<(), SetFromStrError> {
+        Err(SetFromStrError::UnknownLabel(name.to_string()))
+    }
+}
+
+impl<L, T> SetFromStr for LVCons<L, T>
+where
+    L: LabelFromStr,
+    T: SetFromStr,
+{
+    fn set_from_str(&mut self, name: &str, value: &str) -> Result<(), SetFromStrError> {
+        if L::NAME == name {
+            self.head.value = L::parse_value(value)
+                .ok_or(SetFromStrError::Unsupported(L::NAME))?
+                .map_err(|error| {
+                    SetFromStrError::Parse(LabeledError {
+                        label: L::NAME,
+                        error,
+                    })
+                })?;
+            Ok(())
+        } else {
+            self.tail.set_from_str(name, value)
+        }
+    }
+}
+
+/// A single named, dynamically-typed value in a [DynRecord](struct.DynRecord.html).
+#[derive(Debug)]
+pub struct DynField {
+    name: String,
+    type_id: TypeId,
+    type_name: &'static str,
+    value: Box<dyn Any>,
+}
+
+impl DynField {
+    /// Creates a new field with the specified name and value.
+    pub fn new<T>(name: impl Into<String>, value: T) -> DynField
+    where
+        T: Any,
+    {
+        DynField {
+            name: name.into(),
+            type_id: TypeId::of::<T>(),
+            type_name: std::any::type_name::<T>(),
+            value: Box::new(value),
+        }
+    }
+    /// Returns the name of this field.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// Returns the `TypeId` of the value of this field.
+    pub fn type_id(&self) -> TypeId {
+        self.type_id
+    }
+    /// Returns the name of the type of the value of this field, as returned by
+    /// `std::any::type_name`.
+    pub fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+    /// Returns a reference to the value of this field.
+    pub fn value(&self) -> &dyn Any {
+        &*self.value
+    }
+    /// Returns a mutable reference to the value of this field.
+    pub fn value_mut(&mut self) -> &mut dyn Any {
+        &mut *self.value
+    }
+}
+
+/// A labeled record whose labels and types are only known at runtime.
+///
+/// A `DynRecord` is an ordered list of named, dynamically-typed [fields](struct.DynField.html).
+/// Label-value cons-lists can be converted into a `DynRecord` (using `From`), and a `DynRecord` can
+/// be converted back into a label-value cons-list (using `TryFrom`) if it contains exactly one field
+/// for each label in the list, with the label's [NAME](trait.Label.html#associatedconstant.NAME)
+/// and a value of the label's associated type. Fields are matched by name, so they can be in any
+/// order.
+///
+/// ## Example
+/// ```
+/// # #[macro_use] extern crate lhlist;
+/// use std::convert::TryFrom;
+/// use lhlist::{DynRecord, DynRecordError};
+/// # fn main() {
+/// new_label![Name: String];
+/// new_label![Age: u8];
+///
+/// let record = DynRecord::from(lhlist![Name = "Jane".to_string(), Age = 37]);
+/// assert_eq!(record.names(), vec!["Name", "Age"]);
+/// assert_eq!(record.get("Age").and_then(|age| age.downcast_ref::<u8>()), Some(&37));
+///
+/// let list = <LHList![Name, Age]>::try_from(record).unwrap();
+/// assert_eq!(list, lhlist![Name = "Jane".to_string(), Age = 37]);
+///
+/// // records built at runtime
+/// let mut record = DynRecord::new();
+/// record.push("Age", 37u32);
+/// record.push("Name", "Jane".to_string());
+/// assert_eq!(
+///     <LHList![Name, Age]>::try_from(record).unwrap_err(),
+///     DynRecordError::TypeMismatch { label: "Age", expected: "u8", found: "u32" }
+/// );
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct DynRecord {
+    fields: Vec<DynField>,
+}
+
+impl DynRecord {
+    /// Creates a new empty record.
+    pub fn new() -> DynRecord {
+        DynRecord::default()
+    }
+    /// Adds a field to the end of this record.
+    pub fn push<T>(&mut self, name: impl Into<String>, value: T)
+    where
+        T: Any,
+    {
+        self.fields.push(DynField::new(name, value));
+    }
+    /// Returns the fields of this record, in order.
+    pub fn fields(&self) -> &[DynField] {
+        &self.fields
+    }
+    /// Returns the number of fields in this record.
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+    /// Returns `true` if this record has no fields.
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+    /// Returns the names of the fields of this record, in order.
+    pub fn names(&self) -> Vec<&str> {
+        self.fields.iter().map(|field| field.name()).collect()
+    }
+    /// Returns a reference to the value of the first field with the specified name, if it exists.
+    pub fn get(&self, name: &str) -> Option<&dyn Any> {
+        self.fields
+            .iter()
+            .find(|field| field.name == name)
+            .map(|field| field.value())
+    }
+    /// Returns a mutable reference to the value of the first field with the specified name, if it
+    /// exists.
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut dyn Any> {
+        self.fields
+            .iter_mut()
+            .find(|field| field.name == name)
+            .map(|field| field.value_mut())
+    }
+}
+
+impl From<Vec<DynField>> for DynRecord {
+    fn from(fields: Vec<DynField>) -> DynRecord {
+        DynRecord { fields }
+    }
+}
+
+impl From<DynRecord> for Vec<DynField> {
+    fn from(record: DynRecord) -> Vec<DynField> {
+        record.fields
+    }
+}
+
+/// Error produced when converting a [DynRecord](struct.DynRecord.html) into a label-value
+/// cons-list.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub enum DynRecordError {
+    /// The record has no field for a label
+    MissingField(&'static str),
+    /// The record's field for a label has a value of the wrong type
+    TypeMismatch {
+        /// Name of the label
+        label: &'static str,
+        /// Name of the label's associated type
+        expected: &'static str,
+        /// Name of the type of the field's value
+        found: &'static str,
+    },
+    /// The record has a field which doesn't match any label
+    UnexpectedField(String),
+}
+
+impl fmt::Display for DynRecordError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DynRecordError::MissingField(label) => write!(f, "missing field: {}", label),
+            DynRecordError::TypeMismatch {
+                label,
+                expected,
+                found,
+            } => write!(
+                f,
+                "type mismatch for {}: expected {}, found {}",
+                label, expected, found
+            ),
+            DynRecordError::UnexpectedField(name) => write!(f, "unexpected field: {}", name),
+        }
+    }
+}
+
+impl Error for DynRecordError {}
+
+/// Conversion between label-value cons-lists and the fields of a
+/// [DynRecord](struct.DynRecord.html).
+pub trait DynFields: Sized {
+    /// Moves the values of this list into `fields`, in list order
+    fn into_dyn_fields(self, fields: &mut Vec<DynField>);
+    /// Removes the fields for the labels of this list from `fields`, and assembles them into a list
+    fn from_dyn_fields(fields: &mut Vec<DynField>) -> Result<Self, DynRecordError>;
+}
+
+impl DynFields for Nil {
+    fn into_dyn_fields(self, _fields: &mut Vec<DynField>) {}
+    fn from_dyn_fields(_fields: &mut Vec<DynField>) -> Result<Nil, DynRecordError> {
+        Ok(Nil)
+    }
+}
+
+impl<L, T> DynFields for LVCons<L, T>
+where
+    L: Label,
+    L::AssocType: Any,
+    T: DynFields,
+{
+    fn into_dyn_fields(self, fields: &mut Vec<DynField>) {
+        fields.push(DynField::new(L::NAME, self.head.value));
+        self.tail.into_dyn_fields(fields);
+    }
+    fn from_dyn_fields(fields: &mut Vec<DynField>) -> Result<Self, DynRecordError> {
+        let idx = fields
+            .iter()
+            .position(|field| field.name == L::NAME)
+            .ok_or(DynRecordError::MissingField(L::NAME))?;
+        if fields[idx].type_id != TypeId::of::<L::AssocType>() {
+            return Err(DynRecordError::TypeMismatch {
+                label: L::NAME,
+                expected: std::any::type_name::<L::AssocType>(),
+                found: fields[idx].type_name,
+            });
+        }
+        let value = fields
+            .remove(idx)
+            .value
+            .downcast::<L::AssocType>()
+            .expect("type id mismatch");
+        Ok(Cons {
+            head: LabeledValue::new(*value),
+            tail: T::from_dyn_fields(fields)?,
+        })
+    }
+}
+
+impl From<Nil> for DynRecord {
+    fn from(list: Nil) -> DynRecord {
+        into_dyn_record(list)
+    }
+}
+
+impl<L, T> From<LVCons<L, T>> for DynRecord
+where
+    L: Label,
+    LVCons<L, T>: DynFields,
+{
+    fn from(list: LVCons<L, T>) -> DynRecord {
+        into_dyn_record(list)
+    }
+}
+
+impl TryFrom<DynRecord> for Nil {
+    type Error = DynRecordError;
+
+    fn try_from(record: DynRecord) -> Result<Nil, DynRecordError> {
+        try_from_dyn_record(record)
+    }
+}
+
+impl<L, T> TryFrom<DynRecord> for LVCons<L, T>
+where
+    L: Label,
+    LVCons<L, T>: DynFields,
+{
+    type Error = DynRecordError;
+
+    fn try_from(record: DynRecord) -> Result<LVCons<L, T>, DynRecordError> {
+        try_from_dyn_record(record)
+    }
+}
+
+fn into_dyn_record<List>(list: List) -> DynRecord
+where
+    List: DynFields,
+{
+    let mut fields = vec![];
+    list.into_dyn_fields(&mut fields);
+    DynRecord { fields }
+}
+
+fn try_from_dyn_record<List>(record: DynRecord) -> Result<List, DynRecordError>
+where
+    List: DynFields,
+{
+    let mut fields = record.fields;
+    let list = List::from_dyn_fields(&mut fields)?;
+    match fields.into_iter().next() {
+        Some(field) => Err(DynRecordError::UnexpectedField(field.name)),
+        None => Ok(list),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+
+    use crate::*;
+
+    #[label(type=u8, crate=crate)]
+    struct Label1;
+    #[label(type=String, crate=crate)]
+    struct Label2;
+    #[label(name="Third", type=f64, crate=crate)]
+    struct Label3;
+    #[label(type=Vec<u8>, crate=crate)]
+    struct Label4;
+    #[label(type=LHList![Label1, Label2], nested, crate=crate)]
+    struct Label5;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+    #[label(type=Point, crate=crate)]
+    struct Label6;
+
+    #[test]
+    fn get_dyn() {
+        let mut list = lhlist![Label1 = 4, Label2 = "foo".to_string(), Label3 = 1.5];
+        assert_eq!(
+            list.get_dyn("Label1").unwrap().downcast_ref::<u8>(),
+            Some(&4)
+        );
+        assert_eq!(
+            list.get_dyn("Third").unwrap().downcast_ref::<f64>(),
+            Some(&1.5)
+        );
+        assert!(list
+            .get_dyn("Label1")
+            .unwrap()
+            .downcast_ref::<u16>()
+            .is_none());
+        assert!(list.get_dyn("Label3").is_none());
+
+        *list
+            .get_dyn_mut("Label2")
+            .unwrap()
+            .downcast_mut::<String>()
+            .unwrap() = "bar".to_string();
+        assert_eq!(list[Label2], "bar");
+        assert!(list.get_dyn_mut("Label4").is_none());
+    }
+
+    #[test]
+    fn set_from_str() {
+        let mut list = lhlist![Label1 = 4, Label2 = "foo".to_string(), Label3 = 1.5];
+        list.set_from_str("Label1", "9").unwrap();
+        list.set_from_str("Label2", "bar").unwrap();
+        list.set_from_str("Third", "-2.5").unwrap();
+        assert_eq!(
+            list,
+            lhlist![Label1 = 9, Label2 = "bar".to_string(), Label3 = -2.5]
+        );
+
+        assert_eq!(
+            list.set_from_str("Label3", "1.0"),
+            Err(SetFromStrError::UnknownLabel("Label3".to_string()))
+        );
+        let err = list.set_from_str("Label1", "256").unwrap_err();
+        assert_eq!(
+            err,
+            SetFromStrError::Parse(LabeledError {
+                label: "Label1",
+                error: "number too large to fit in target type".to_string()
+            })
+        );
+        assert_eq!(
+            err.to_string(),
+            "parse error for Label1: number too large to fit in target type"
+        );
+        assert_eq!(list[Label1], 9);
+    }
+
+    #[test]
+    fn set_from_str_unsupported() {
+        assert_eq!(Label1::parse_value("7"), Some(Ok(7)));
+        assert_eq!(Label4::parse_value("[1]"), None);
+        assert_eq!(Label6::parse_value("1,2"), None);
+
+        let mut list = lhlist![
+            Label1 = 4,
+            Label4 = vec![1, 2],
+            Label5 = lhlist![Label1 = 1, Label2 = "foo".to_string()],
+            Label6 = Point { x: 1, y: 2 },
+        ];
+        list.set_from_str("Label1", "5").unwrap();
+        assert_eq!(list[Label1], 5);
+        assert_eq!(
+            list.set_from_str("Label4", "[3]"),
+            Err(SetFromStrError::Unsupported("Label4"))
+        );
+        assert_eq!(
+            list.set_from_str("Label5", "bar"),
+            Err(SetFromStrError::Unsupported("Label5"))
+        );
+        let err = list.set_from_str("Label6", "3,4").unwrap_err();
+        assert_eq!(err, SetFromStrError::Unsupported("Label6"));
+        assert_eq!(
+            err.to_string(),
+            "values of Label6 can't be parsed from strings"
+        );
+        assert_eq!(list[Label4], vec![1, 2]);
+        assert_eq!(list[Label6], Point { x: 1, y: 2 });
+        assert_eq!(
+            list.set_from_str("Label2", "bar"),
+            Err(SetFromStrError::UnknownLabel("Label2".to_string()))
+        );
+
+        let mut list = lhlist!["name" = "Jane".to_string(), "age" = 37u8];
+        list.set_from_str("age", "38").unwrap();
+        assert_eq!(list.value::<StrLabel!["age"]>(), &38);
+    }
+
+    #[label(type=Option<u8>, crate=crate)]
+    struct Label7;
+    #[label(type=Result<Vec<u8>, String>, crate=crate)]
+    struct Label8;
+
+    #[test]
+    fn set_from_str_wrapped() {
+        assert_eq!(Label7::parse_value("3"), Some(Ok(Some(3))));
+        assert!(Label7::parse_value("x").unwrap().is_err());
+
+        let mut list = lhlist![Label7 = None];
+        list.set_from_str("Label7", "5").unwrap();
+        assert_eq!(list[Label7], Some(5));
+
+        let mut list = list.transpose_option().unwrap();
+        list.set_from_str("Label7", "6").unwrap();
+        assert_eq!(list[Label7], 6);
+        assert_eq!(
+            list.set_from_str("Label7", "256"),
+            Err(SetFromStrError::Parse(LabeledError {
+                label: "Label7",
+                error: "number too large to fit in target type".to_string()
+            }))
+        );
+
+        let mut list = lhlist![Label8 = Ok(vec![1])].transpose_result().unwrap();
+        assert_eq!(
+            list.set_from_str("Label8", "[2]"),
+            Err(SetFromStrError::Unsupported("Label8"))
+        );
+    }
+
+    #[test]
+    fn dyn_record() {
+        let record = DynRecord::from(lhlist![
+            Label1 = 4,
+            Label2 = "foo".to_string(),
+            Label3 = 1.5
+        ]);
+        assert_eq!(record.len(), 3);
+        assert_eq!(record.names(), vec!["Label1", "Label2", "Third"]);
+        assert_eq!(record.fields()[0].type_id(), std::any::TypeId::of::<u8>());
+        assert_eq!(record.fields()[2].type_name(), "f64");
+        assert_eq!(
+            record.get("Third").unwrap().downcast_ref::<f64>(),
+            Some(&1.5)
+        );
+        assert!(DynRecord::from(Nil).is_empty());
+
+        type List = LHList![Label1, Label2, Label3];
+        let list = List::try_from(record).unwrap();
+        assert_eq!(
+            list,
+            lhlist![Label1 = 4, Label2 = "foo".to_string(), Label3 = 1.5]
+        );
+
+        let mut record = DynRecord::new();
+        record.push("Third", 2.5f64);
+        record.push("Label1", 9u8);
+        record.push("Label2", "bar".to_string());
+        *record
+            .get_mut("Label1")
+            .unwrap()
+            .downcast_mut::<u8>()
+            .unwrap() += 1;
+        assert_eq!(
+            List::try_from(record),
+            Ok(lhlist![
+                Label1 = 10,
+                Label2 = "bar".to_string(),
+                Label3 = 2.5
+            ])
+        );
+    }
+
+    #[test]
+    fn dyn_record_errors() {
+        type List = LHList![Label1, Label2];
+
+        let record = DynRecord::from(vec![DynField::new("Label1", 4u8)]);
+        assert_eq!(
+            List::try_from(record),
+            Err(DynRecordError::MissingField("Label2"))
+        );
+
+        let record = DynRecord::from(vec![
+            DynField::new("Label1", 4u8),
+            DynField::new("Label2", "foo"),
+        ]);
+        let err = List::try_from(record).unwrap_err();
+        assert_eq!(
+            err,
+            DynRecordError::TypeMismatch {
+                label: "Label2",
+                expected: "alloc::string::String",
+                found: "&str"
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "type mismatch for Label2: expected alloc::string::String, found &str"
+        );
+
+        let mut record = DynRecord::from(lhlist![Label1 = 4, Label2 = "foo".to_string()]);
+        record.push("Label4", ());
+        assert_eq!(
+            List::try_from(record),
+            Err(DynRecordError::UnexpectedField("Label4".to_string()))
+        );
+        assert_eq!(
+            Nil::try_from(DynRecord::from(vec![DynField::new("Label1", 4u8)])),
+            Err(DynRecordError::UnexpectedField("Label1".to_string()))
+        );
+    }
+}
@@ -464,7 +464,7 @@ mod tests {
         impl MapFunc<&Vec<usize>> for DoStuff {
             type Output = usize;
             fn call(&mut self, item: &Vec<usize>) -> usize {
-                item.iter().fold(0, |acc, value| acc + value)
+                item.iter().sum()
             }
         }
         impl MapFunc<&Vec<&str>> for DoStuff {
@@ -2,6 +2,51 @@ use typenum::Unsigned;
 
 use crate::cons::{Cons, LCons, LVCons, Len, Nil};
 
+/// Implements `Debug`, `Clone`, `Copy`, `Default`, `PartialEq`, `Eq`, and `Hash` for a label type
+/// which is a tuple struct containing only a `PhantomData`, without requiring these traits of its
+/// type parameters. `Debug` shows the label's name. Also implements `LabelNested`, since derived
+/// labels are never nested.
+macro_rules! impl_label_traits {
+    ($name:ident<$($lt:lifetime,)* $($param:ident),+>) => {
+        impl<$($lt,)* $($param),+> ::std::fmt::Debug for $name<$($lt,)* $($param),+>
+        where
+            Self: $crate::Label,
+        {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.debug_tuple(stringify!($name))
+                    .field(&<Self as $crate::Label>::NAME)
+                    .finish()
+            }
+        }
+        impl<$($lt,)* $($param),+> Clone for $name<$($lt,)* $($param),+> {
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+        impl<$($lt,)* $($param),+> Copy for $name<$($lt,)* $($param),+> {}
+        impl<$($lt,)* $($param),+> Default for $name<$($lt,)* $($param),+> {
+            fn default() -> Self {
+                $name(::std::marker::PhantomData)
+            }
+        }
+        impl<$($lt,)* $($param),+> PartialEq for $name<$($lt,)* $($param),+> {
+            fn eq(&self, _other: &Self) -> bool {
+                true
+            }
+        }
+        impl<$($lt,)* $($param),+> Eq for $name<$($lt,)* $($param),+> {}
+        impl<$($lt,)* $($param),+> ::std::hash::Hash for $name<$($lt,)* $($param),+> {
+            fn hash<H: ::std::hash::Hasher>(&self, _state: &mut H) {}
+        }
+        impl<$($lt,)* $($param),+> $crate::LabelNested for $name<$($lt,)* $($param),+>
+        where
+            Self: $crate::Label,
+        {
+            type Nested = $crate::False;
+        }
+    };
+}
+
 /// A trait with information about a label.
 ///
 /// Typically, labels are simply unit-like structs used to identify elements in a list. This trait
@@ -227,12 +272,50 @@ where
 /// assert_eq!(MyLabel3::name(), "My Custom Label");
 /// # }
 /// ```
+///
+/// A [default value](trait.LabelDefault.html) can be added to labels with associated types using
+/// `new_label![MyLabel: u8 = 4]` or `#[label(type=u8, default=4)]`:
+/// ```
+/// # #[macro_use] extern crate lhlist;
+/// # fn main() {
+/// use lhlist::LabelDefault;
+/// new_label![MyLabel1: u8 = 4];
+/// assert_eq!(MyLabel1::default_value(), 4);
+///
+/// #[label(name="My Custom Label", type=Vec<u32>, default=vec![1, 2])]
+/// struct MyLabel2;
+/// assert_eq!(MyLabel2::default_value(), vec![1, 2]);
+/// # }
+/// ```
+///
+/// [Nested](trait.LabelNested.html) labels can be created using `new_label![MyLabel: nested T]` or
+/// `#[label(type=T, nested)]`:
+/// ```
+/// # #[macro_use] extern crate lhlist;
+/// # fn main() {
+/// use lhlist::FlatLabels;
+/// new_label![City: &'static str];
+/// new_label![Address: nested LHList![City]];
+/// new_label![Customer("customer"): nested LHList![Address]];
+///
+/// let customer = lhlist![Customer = lhlist![Address = lhlist![City = "Springfield"]]];
+/// assert_eq!(customer.flat_labels(), vec!["customer.Address.City"]);
+/// # }
+/// ```
 #[macro_export]
 macro_rules! new_label {
     ($id:ident) => {
         #[label]
         struct $id;
     };
+    ($id:ident: nested $type:ty) => {
+        #[label(type=$type, nested)]
+        struct $id;
+    };
+    ($id:ident($name:expr): nested $type:ty) => {
+        #[label(name=$name, type=$type, nested)]
+        struct $id;
+    };
     ($id:ident: $type:ty) => {
         #[label(type=$type)]
         struct $id;
@@ -241,6 +324,14 @@ macro_rules! new_label {
         #[label(name=$name, type=$type)]
         struct $id;
     };
+    ($id:ident: $type:ty = $default:expr) => {
+        #[label(type=$type, default=$default)]
+        struct $id;
+    };
+    ($id:ident($name:expr): $type:ty = $default:expr) => {
+        #[label(name=$name, type=$type, default=$default)]
+        struct $id;
+    };
 }
 
 /// Macro for creating type signature for a [LCons](type.LCons.html) label-only cons-list.
@@ -249,37 +340,58 @@ macro_rules! new_label {
 /// that require them.
 #[macro_export]
 macro_rules! Labels {
-    () => ( $crate::Nil );
-    ($label:ty) => (
-        $crate::LCons<$label, $crate::Nil>
-    );
-    ($label:ty, $($rest:tt)*) => (
-        $crate::LCons<$label, Labels![$($rest)*]>
-    )
+    ($($tokens:tt)*) => ( $crate::__Labels![$crate; $($tokens)*] );
+}
+
+/// Macro for creating type signature for a [LVCons](type.LVCons.html) label-value cons-list.
+///
+/// `LHList![A, B]` is the type of the list created by `lhlist![A = <value>, B = <value>]`, and is
+/// equivalent to `LVCons<A, LVCons<B, Nil>>`. The [Record](macro.Record.html) macro is an alias
+/// for this macro.
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate lhlist;
+/// # fn main() {
+/// new_label![Name: &'static str];
+/// new_label![Age: u8];
+///
+/// struct Person {
+///     info: Record![Name, Age],
+/// }
+///
+/// fn age_of(person: &LHList![Name, Age]) -> u8 {
+///     person[Age]
+/// }
+///
+/// let person = Person { info: lhlist![Name = "Jane", Age = 37] };
+/// assert_eq!(age_of(&person.info), 37);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! LHList {
+    ($($tokens:tt)*) => ( $crate::__LHList![$crate; $($tokens)*] );
+}
+
+/// Macro for creating type signature for a [LVCons](type.LVCons.html) label-value cons-list.
+///
+/// Alias for the [LHList](macro.LHList.html) macro.
+#[macro_export]
+macro_rules! Record {
+    ($($tokens:tt)*) => ( $crate::__LHList![$crate; $($tokens)*] );
 }
 
 /// Macro for creating an instance of an [LCons](type.LCons.html) label-only cons-lists.
 #[macro_export]
 macro_rules! labels {
-    () => ( $crate::Nil );
-    ($label:ty) => (
-        $crate::Cons {
-            head: std::marker::PhantomData::<$label>,
-            tail: $crate::Nil
-        }
-    );
-    ($label:ty, $($rest:tt)*) => (
-        $crate::Cons {
-            head: std::marker::PhantomData::<$label>,
-            tail: labels![$($rest)*]
-        }
-    );
+    ($($tokens:tt)*) => ( $crate::__labels![$crate; $($tokens)*] );
 }
 
 /// Macro for creating labeled heterogeneous lists.
 ///
 /// Create an [LVCons](type.LVCons.html) label-value cons-list based on a comma-separated list
-/// of `<label> = <value>` assignments.
+/// of `<label> = <value>` assignments. Labels can either be label types, or string literals (see
+/// [StrLabel](struct.StrLabel.html)).
 ///
 /// # Example
 /// ```
@@ -304,21 +416,92 @@ macro_rules! labels {
 /// assert_eq!(solar_system[Age], 4.568);
 /// # }
 /// ```
+///
+/// The macro can also be used by path, without importing it:
+/// ```
+/// #[lhlist::label(type=u8)]
+/// struct Count;
+///
+/// let list = lhlist::lhlist![Count = 4];
+/// assert_eq!(list[Count], 4);
+/// ```
+///
+/// Each label can only appear once in a list:
+/// ```compile_fail
+/// # #[macro_use] extern crate lhlist;
+/// # fn main() {
+/// new_label![Count: u8];
+/// let list = lhlist![Count = 4, Count = 5];
+/// # }
+/// ```
 #[macro_export]
 macro_rules! lhlist {
-    () => ( $crate::Nil );
-    ($label:ty = $value:expr) => (
-        $crate::Cons {
-            head: $crate::LabeledValue::<$label>::new($value),
-            tail: $crate::Nil
-        }
-    );
-    ($label:ty = $value:expr, $($rest:tt)*) => (
-        $crate::Cons {
-            head: $crate::LabeledValue::<$label>::new($value),
-            tail: lhlist![$($rest)*]
-        }
-    );
+    ($($tokens:tt)*) => ( $crate::__lhlist![$crate; $($tokens)*] );
+}
+
+/// Macro for destructuring labeled heterogeneous lists by label.
+///
+/// Binds the values of an [LVCons](type.LVCons.html) label-value cons-list to patterns using
+/// `{ <label>: <pattern>, ... } = <list>;` statements. Labels can be specified in any order.
+///
+/// Statements whose patterns bind by value consume the list. Statements with any `ref` or
+/// `ref mut` binding borrow the list instead (see [as_refs](trait.AsRefs.html#tymethod.as_refs)):
+/// `ref name` binds a reference to the value in the list, and `ref mut name` binds a mutable
+/// reference. Statements which borrow the list can also contain `_` and reference patterns (such as
+/// `&name`), but mixing `ref` and by-value bindings in one statement fails to compile.
+///
+/// By default, every label in the list must be bound. A trailing `..` ignores any remaining
+/// elements, while `..<pattern>` binds the remaining elements as a new list. Specifying a label
+/// that is not in the list fails to compile.
+///
+/// # Example
+/// ```
+/// # #[macro_use] extern crate lhlist;
+/// # fn main() {
+/// new_label![Name: String];
+/// new_label![Age: u8];
+/// new_label![Height: f32];
+///
+/// let mut person = lhlist![Name = "Jane".to_string(), Age = 37, Height = 1.68];
+/// let_lhlist! { { Name: ref mut name, .. } = person; }
+/// name.push_str(" Doe");
+///
+/// let_lhlist! { { Age: &age, Name: ref name, .. } = person; }
+/// assert_eq!(age, 37);
+/// assert_eq!(name, "Jane Doe");
+///
+/// let_lhlist! { { Height: height, ..rest } = person; }
+/// assert_eq!(height, 1.68);
+/// assert_eq!(rest, lhlist![Name = "Jane Doe".to_string(), Age = 37]);
+/// # }
+/// ```
+///
+/// Labels must exist in the list:
+/// ```compile_fail
+/// # #[macro_use] extern crate lhlist;
+/// # fn main() {
+/// new_label![Name: String];
+/// new_label![Age: u8];
+///
+/// let person = lhlist![Name = "Jane".to_string()];
+/// let_lhlist! { { Age: age, .. } = person; }
+/// # }
+/// ```
+///
+/// `ref` and by-value bindings can't be mixed:
+/// ```compile_fail
+/// # #[macro_use] extern crate lhlist;
+/// # fn main() {
+/// new_label![Name: String];
+/// new_label![Age: u8];
+///
+/// let person = lhlist![Name = "Jane".to_string(), Age = 37];
+/// let_lhlist! { { Name: name, Age: ref age } = person; }
+/// # }
+/// ```
+#[macro_export]
+macro_rules! let_lhlist {
+    ($($tokens:tt)*) => ( $crate::__let_lhlist!{$crate; $($tokens)*} );
 }
 
 #[cfg(test)]
@@ -338,7 +521,7 @@ mod tests {
         assert_eq!(Label1::name(), "My Label");
 
         assert_eq!(Label2::name(), "Label2");
-        assert_eq!(<Label2 as Label>::AssocType::max_value(), 255u8);
+        assert_eq!(<Label2 as Label>::AssocType::MAX, 255u8);
 
         assert_eq!(Label3::name(), "Label3");
     }
@@ -363,10 +546,89 @@ mod tests {
         let labels_only = test_list.labels_only();
         let iter = labels_only.iter();
         let (item, iter) = iter.next();
-        assert_eq!(item, &PhantomData::<Label1>::default());
+        assert_eq!(item, &PhantomData::<Label1>);
         let (item, iter) = iter.next();
-        assert_eq!(item, &PhantomData::<Label2>::default());
+        assert_eq!(item, &PhantomData::<Label2>);
         let (item, _) = iter.next();
-        assert_eq!(item, &PhantomData::<Label3>::default());
+        assert_eq!(item, &PhantomData::<Label3>);
+    }
+
+    #[test]
+    fn lhlist_type() {
+        #[label(type=u8, crate=crate)]
+        struct Label1;
+        #[label(type=&'static str, crate=crate)]
+        struct Label2;
+
+        fn first<L: LookupElemByLabel<Label1, Elem = LabeledValue<Label1>>>(list: &L) -> u8 {
+            list.elem().value
+        }
+
+        let list: LHList![Label1, Label2] = lhlist![Label1 = 2, Label2 = "foo"];
+        let record: Record![Label1, Label2] = list.clone();
+        let explicit: LVCons<Label1, LVCons<Label2, Nil>> = record;
+        assert_eq!(first(&explicit), 2);
+        let _empty: LHList![] = Nil;
+    }
+
+    #[test]
+    fn let_lhlist() {
+        #[label(type=String, crate=crate)]
+        struct Label1;
+        #[label(type=u16, crate=crate)]
+        struct Label2;
+        #[label(type=(i16, i16), crate=crate)]
+        struct Label3;
+        let test_list = lhlist![Label1 = "foo".to_string(), Label2 = 301, Label3 = (-5, 2)];
+
+        let_lhlist! {
+            { Label3: (x, y), Label1: s, Label2: mut n } = test_list.clone();
+        }
+        let _: String = s.clone();
+        let _: u16 = n;
+        n += 1;
+        assert_eq!((x, y), (-5, 2));
+        assert_eq!(s, "foo");
+        assert_eq!(n, 302);
+
+        // bindings by reference borrow the list
+        let mut test_list = test_list;
+        let_lhlist! {
+            { Label3: &(x, _), Label1: ref s, Label2: ref n } = test_list;
+        }
+        let _: i16 = x;
+        let _: &String = s;
+        let _: &u16 = n;
+        assert_eq!(x, -5);
+        assert_eq!(s, "foo");
+        assert_eq!(n, &301);
+        let_lhlist! {
+            { Label1: ref mut s, Label2: ref mut n, Label3: ref t } = test_list;
+        }
+        let _: &(i16, i16) = t;
+        let _: &mut u16 = n;
+        s.push_str("bar");
+        *n += 1;
+        assert_eq!(t, &(-5, 2));
+        assert_eq!(
+            test_list,
+            lhlist![
+                Label1 = "foobar".to_string(),
+                Label2 = 302,
+                Label3 = (-5, 2)
+            ]
+        );
+        let test_list = lhlist![Label1 = "foo".to_string(), Label2 = 301, Label3 = (-5, 2)];
+
+        let_lhlist! {
+            { Label2: n, .. } = test_list.clone();
+            { Label1: s, ..rest } = test_list;
+        }
+        assert_eq!(n, 301);
+        assert_eq!(s, "foo".to_string());
+        assert_eq!(rest, lhlist![Label2 = 301, Label3 = (-5, 2)]);
+
+        let_lhlist! { { ..rest } = rest; }
+        assert_eq!(rest, lhlist![Label2 = 301, Label3 = (-5, 2)]);
     }
 }
@@ -0,0 +1,159 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::cons::{LCons, Nil};
+use crate::nested::{Append, Path, PathMut};
+
+/// First-class accessor for a value within nested label-value cons-lists.
+///
+/// A lens is defined by a path of labels `P` (an [LCons](type.LCons.html) label-only cons-list,
+/// typically created with the [Labels](macro.Labels.html) macro), and looks up values with
+/// [Path](trait.Path.html) and [PathMut](trait.PathMut.html). The first label is looked up in the
+/// list the lens is applied to, and each following label is looked up in the value found by the
+/// previous label. Lenses can be extended with [then](struct.Lens.html#method.then).
+///
+/// ## Example
+/// ```
+/// # #[macro_use] extern crate lhlist;
+/// use lhlist::Lens;
+/// # fn main() {
+/// new_label![City: String];
+/// new_label![Address: LHList![City]];
+/// new_label![Name: &'static str];
+/// new_label![Customer: LHList![Name, Address]];
+///
+/// let mut order = lhlist![Customer = lhlist![
+///     Name = "Jane",
+///     Address = lhlist![City = "Springfield".to_string()],
+/// ]];
+///
+/// let address = Lens::<Labels![Customer, Address]>::new();
+/// let city = address.then::<City>();
+/// assert_eq!(city.get(&order), "Springfield");
+///
+/// city.set(&mut order, "Shelbyville".to_string());
+/// city.modify(&mut order, |city| city.push_str(", IL"));
+/// assert_eq!(address.get(&order), &lhlist![City = "Shelbyville, IL".to_string()]);
+/// # }
+/// ```
+pub struct Lens<P> {
+    _path: PhantomData<P>,
+}
+
+impl<P> Lens<P> {
+    /// Creates a new lens for the labels in `P`
+    pub fn new() -> Lens<P> {
+        Lens { _path: PhantomData }
+    }
+
+    /// Creates a new lens which looks up label `L` in the value focused by this lens
+    pub fn then<L>(self) -> Lens<<P as Append<LCons<L, Nil>>>::Output>
+    where
+        P: Append<LCons<L, Nil>>,
+    {
+        Lens::new()
+    }
+
+    /// Returns a reference to the value focused by this lens in `list`
+    pub fn get<'a, List>(&self, list: &'a List) -> &'a List::Output
+    where
+        List: Path<P>,
+        P: 'a,
+    {
+        list.path()
+    }
+
+    /// Returns a mutable reference to the value focused by this lens in `list`
+    pub fn get_mut<'a, List>(&self, list: &'a mut List) -> &'a mut List::Output
+    where
+        List: PathMut<P>,
+        P: 'a,
+    {
+        list.path_mut()
+    }
+
+    /// Replaces the value focused by this lens in `list` with `value`, returning the old value
+    pub fn set<List>(&self, list: &mut List, value: List::Output) -> List::Output
+    where
+        List: PathMut<P>,
+        List::Output: Sized,
+    {
+        std::mem::replace(list.path_mut(), value)
+    }
+
+    /// Modifies the value focused by this lens in `list` with `f`
+    pub fn modify<List, F>(&self, list: &mut List, f: F)
+    where
+        List: PathMut<P>,
+        F: FnOnce(&mut List::Output),
+    {
+        f(list.path_mut())
+    }
+}
+
+impl<P> fmt::Debug for Lens<P> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Lens").finish()
+    }
+}
+impl<P> Clone for Lens<P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<P> Copy for Lens<P> {}
+impl<P> Default for Lens<P> {
+    fn default() -> Self {
+        Lens::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[label(type=String, crate=crate)]
+    struct City;
+    #[label(type=u32, crate=crate)]
+    struct Zip;
+    #[label(type=LHList![City, Zip], crate=crate)]
+    struct Address;
+    #[label(type=String, crate=crate)]
+    struct Name;
+    #[label(type=LHList![Name, Address], crate=crate)]
+    struct Customer;
+    #[label(type=u64, crate=crate)]
+    struct Id;
+
+    #[test]
+    fn lens() {
+        let mut order = lhlist![
+            Id = 7,
+            Customer = lhlist![
+                Name = "Jane".to_string(),
+                Address = lhlist![City = "Springfield".to_string(), Zip = 12345],
+            ],
+        ];
+
+        let id = Lens::<Labels![Id]>::new();
+        assert_eq!(id.get(&order), &7);
+        *id.get_mut(&mut order) += 1;
+        assert_eq!(order[Id], 8);
+
+        let address = Lens::<Labels![Customer]>::new().then::<Address>();
+        let zip = address.then::<Zip>();
+        assert_eq!(zip.get(&order), &12345);
+        assert_eq!(zip.set(&mut order, 54321), 12345);
+        zip.modify(&mut order, |zip| *zip += 1);
+        assert_eq!(order[Customer][Address][Zip], 54322);
+
+        let city = Lens::<Labels![Customer, Address, City]>::default();
+        city.get_mut(&mut order).push_str(", IL");
+        assert_eq!(city.get(&order), "Springfield, IL");
+        assert_eq!(city.get(&order), order.path::<(Customer, Address, City)>());
+        assert_eq!(
+            address.get(&order),
+            &lhlist![City = "Springfield, IL".to_string(), Zip = 54322]
+        );
+    }
+}
@@ -24,6 +24,9 @@ assert_eq!(MyLabel::name(), "MyLabel");
 
 See the [new_label](macro.new_label.html) documentation for more examples of creating labels.
 
+For ad-hoc lists, string literals can also be used as labels (see
+[StrLabel](struct.StrLabel.html)), without needing to declare a label struct.
+
 ## List Creation
 
 Labeled lists are created using the [lhlist](macro.lhlist.html) macro, which takes a
@@ -59,16 +62,22 @@ The contained values in a list (without the associated label information) can be
 the [value](struct.Cons.html#method.value) or [value_mut](struct.Cons.html#method.value_mut)
 methods. Using `list[Label]` notation (via `Index` and `IndexMut`) is also supported.
 
+Elements can also be accessed by their position in the list via the [at](struct.Cons.html#method.at)
+or [at_mut](struct.Cons.html#method.at_mut) methods (using `typenum` unsigned integers as
+positions), or by the type of their value via the
+[get_by_type](struct.Cons.html#method.get_by_type) or
+[get_by_type_mut](struct.Cons.html#method.get_by_type_mut) methods.
+
 More details and examples can be found in the documentation for the various accessor methods.
 
 ## Iteration
 
 Much like accessing individual element of a list, iteration over a list can be done in two contexts:
 1. Calling [iter](struct.Cons.html#method.iter) to create a
-[ConsIterator](iter/struct.ConsIterator.html) which iterates over the
-[LabeledValue](struct.LabeledValue.html) objects, or
+   [ConsIterator](iter/struct.ConsIterator.html) which iterates over the
+   [LabeledValue](struct.LabeledValue.html) objects, or
 2. Calling [iter_values](struct.Cons.html#method.iter_values) to create a
-[ValuesIterator](iter/struct.ValuesIterator.html) which iterates over the contained values.
+   [ValuesIterator](iter/struct.ValuesIterator.html) which iterates over the contained values.
 
 These iterators both support [mapping](iter/struct.MapAdapter.html) functionality for processing
 values using types that implement the [MapFunc](iter/trait.MapFunc.html) trait. See the
@@ -76,35 +85,99 @@ values using types that implement the [MapFunc](iter/trait.MapFunc.html) trait.
 
 There are also two ways to collect the contents of an iterator into a new cons-list:
 1. [CollectIntoHList](iter/trait.CollectIntoHList.html) collects the contents of an iterator as-is.
-When this collection is performed on a [ValuesIterator](iter/struct.ValuesIterator.html), the new
-cons-list does not contain any label information (since the `ValueIterator` only iterates over the
-contained values).
+   When this collection is performed on a [ValuesIterator](iter/struct.ValuesIterator.html), the new
+   cons-list does not contain any label information (since the `ValueIterator` only iterates over the
+   contained values).
 2. [CollectIntoLabeledHList](iter/trait.CollectIntoLabeledHList.html) collects the contents of an
-iterator with a new provided set of labels. This is particularly useful when the types of elements
-have changed during the iterator process (via [MapAdapter](iter/struct.MapAdapter.html)) and the
-old labels are no longer valid (since a label can only has one associated type).
+   iterator with a new provided set of labels. This is particularly useful when the types of elements
+   have changed during the iterator process (via [MapAdapter](iter/struct.MapAdapter.html)) and the
+   old labels are no longer valid (since a label can only has one associated type).
 
 An example of both kinds of collection can be see in the [MapAdapter](iter/struct.MapAdapter.html)
 documentation.
+
+## Coproducts
+
+Where a labeled list contains a value for every label, a labeled coproduct
+([LVCoprod](type.LVCoprod.html)) contains a value for exactly one of its labels. Its type is written
+using the [Coprod](macro.Coprod.html) macro.
 */
 
 #![warn(missing_docs)]
 
 extern crate label_attribute;
-extern crate typenum;
+pub extern crate typenum;
 
 pub use label_attribute::*;
 
 mod cons;
-pub use cons::{cons, Cons, LCons, LVCons, Len, Nil};
+pub use cons::{cons, Cons, LCons, LVCons, Len, LenSize, Nil, SameLength};
 
+#[macro_use]
 mod label;
 pub use label::{labeled, labeled_typearg, HasLabels, Label, LabeledValue, StrLabels, Value};
 
+mod str_label;
+pub use str_label::{Ch, StrLabel, TStr, TypeStr, MAX_STR_LABEL_LEN};
+
+mod defaults;
+pub use defaults::{FillDefaults, LabelDefault, LabelDefaultMatch, LabelDefaultSource};
+
+mod validate;
+pub use validate::{LabelValidate, Validate};
+
+mod dynamic;
+#[doc(hidden)]
+pub use dynamic::{__FromStrProbe, __ParseFromStr, __ParseOption, __ParseUnsupported};
+pub use dynamic::{
+    DynField, DynFields, DynLookup, DynRecord, DynRecordError, LabelFromStr, SetFromStr,
+    SetFromStrError,
+};
+
+mod nested;
+pub use nested::{FlatLabels, Flatten, LabelNested, Path, PathMut};
+
+mod lens;
+pub use lens::Lens;
+
+mod schema;
+pub use schema::{HasSchema, Schema, SchemaField};
+
 mod relation;
-pub use relation::{Bool, False, LabelEq, Member, ToBool, True};
+pub use relation::{And, Bool, False, If, IfElse, LabelEq, Member, Not, Or, ToBool, True};
 
 mod lookup;
-pub use lookup::LookupElemByLabel;
+pub use lookup::{
+    At, Here, IndexOf, LookupElemByLabel, LookupValueByType, LookupValueByTypeMut, Pluck, There,
+};
+
+mod transpose;
+pub use transpose::{LabeledError, TransposeOption, TransposeResult, Unwrapped, Wrapped};
+
+mod partial;
+pub use partial::{Optional, Partial, PartialField, PartialLabels};
+
+mod builder;
+pub use builder::{RecordBuilder, Reorder};
+
+mod reverse;
+pub use reverse::{Init, Last, Reverse};
+
+mod split;
+pub use split::{SplitAt, SplitAtLabel};
+
+mod transform;
+pub use transform::{Relabel, Replace};
+
+mod refs;
+pub use refs::{AsRefs, ClonedRefs, CopiedRefs, Ref, RefMut};
+
+mod visit;
+pub use visit::{Accept, Visitor, VisitorMut};
+
+mod coprod;
+pub use coprod::{
+    CNil, Coprod, CoprodFold, CoprodInjector, CoprodSelector, CoprodUninjector, LVCoprod,
+};
 
 pub mod iter;
@@ -1,5 +1,10 @@
+use std::marker::PhantomData;
+use std::ops::{Add, Sub};
+
+use typenum::{Add1, Sub1, UInt, UTerm, Unsigned, B1};
+
 use crate::cons::Cons;
-use crate::label::Label;
+use crate::label::{Label, Value};
 use crate::relation::{False, LabelEq, Member, True};
 
 /// Lookup a specific element in a list by label.
@@ -13,30 +18,18 @@ pub trait LookupElemByLabel<TargetL> {
 impl<TargetL, L, T> LookupElemByLabel<TargetL> for Cons<L, T>
 where
     L: Label + LabelEq<TargetL>,
-    T: Member<TargetL>,
-    Self: LookupElemByLabelMatch<
-        TargetL,
-        <L as LabelEq<TargetL>>::Output,
-        <T as Member<TargetL>>::Output,
-    >,
+    Self: Member<TargetL, Output = True>,
+    Self: LookupElemByLabelMatch<TargetL, <L as LabelEq<TargetL>>::Output>,
 {
-    type Elem = <Self as LookupElemByLabelMatch<
-        TargetL,
-        <L as LabelEq<TargetL>>::Output,
-        <T as Member<TargetL>>::Output,
-    >>::Elem;
+    type Elem = <Self as LookupElemByLabelMatch<TargetL, <L as LabelEq<TargetL>>::Output>>::Elem;
 
     fn elem(&self) -> &Self::Elem {
-        LookupElemByLabelMatch::<
-            TargetL,
-            <L as LabelEq<TargetL>>::Output,
-            <T as Member<TargetL>>::Output,
-        >::elem(self)
+        LookupElemByLabelMatch::<TargetL, <L as LabelEq<TargetL>>::Output>::elem(self)
     }
 }
 
 /// Helper trait for [LookupElemByLabel](trait.LookupElemByLabel.html).
-pub trait LookupElemByLabelMatch<L, LabelMatch, TailMatch> {
+pub trait LookupElemByLabelMatch<L, HeadMatch> {
     /// The type of the returned element
     type Elem: ?Sized;
     /// Returns a reference to the element from the list
@@ -44,7 +37,7 @@ pub trait LookupElemByLabelMatch<L, LabelMatch, TailMatch> {
 }
 
 // head matches
-impl<TargetL, L, T, TailMatch> LookupElemByLabelMatch<TargetL, True, TailMatch> for Cons<L, T>
+impl<TargetL, L, T> LookupElemByLabelMatch<TargetL, True> for Cons<L, T>
 where
     L: Label,
 {
@@ -55,8 +48,8 @@ where
     }
 }
 
-// head doesn't match but tail does
-impl<TargetL, L, T> LookupElemByLabelMatch<TargetL, False, True> for Cons<L, T>
+// head doesn't match, look in tail
+impl<TargetL, L, T> LookupElemByLabelMatch<TargetL, False> for Cons<L, T>
 where
     L: Label,
     T: LookupElemByLabel<TargetL>,
@@ -77,34 +70,26 @@ pub trait LookupElemByLabelMut<TargetL>: LookupElemByLabel<TargetL> {
 impl<TargetL, L, T> LookupElemByLabelMut<TargetL> for Cons<L, T>
 where
     L: Label + LabelEq<TargetL>,
-    T: Member<TargetL>,
     Self: LookupElemByLabel<TargetL>,
     Self: LookupElemByLabelMutMatch<
         TargetL,
         <L as LabelEq<TargetL>>::Output,
-        <T as Member<TargetL>>::Output,
         Elem = <Self as LookupElemByLabel<TargetL>>::Elem,
     >,
 {
     fn elem_mut(&mut self) -> &mut Self::Elem {
-        LookupElemByLabelMutMatch::<
-            TargetL,
-            <L as LabelEq<TargetL>>::Output,
-            <T as Member<TargetL>>::Output,
-        >::elem_mut(self)
+        LookupElemByLabelMutMatch::<TargetL, <L as LabelEq<TargetL>>::Output>::elem_mut(self)
     }
 }
 
 /// Helper trait for [LookupElemByLabelMut](trait.LookupElemByLabelMut.html).
-pub trait LookupElemByLabelMutMatch<L, LabelMatch, TailMatch>:
-    LookupElemByLabelMatch<L, LabelMatch, TailMatch>
-{
+pub trait LookupElemByLabelMutMatch<L, HeadMatch>: LookupElemByLabelMatch<L, HeadMatch> {
     /// Returns a mutable reference to the element from the list
     fn elem_mut(&mut self) -> &mut Self::Elem;
 }
 
 // head matches
-impl<TargetL, L, T, TailMatch> LookupElemByLabelMutMatch<TargetL, True, TailMatch> for Cons<L, T>
+impl<TargetL, L, T> LookupElemByLabelMutMatch<TargetL, True> for Cons<L, T>
 where
     L: Label,
 {
@@ -113,8 +98,8 @@ where
     }
 }
 
-// head doesn't match but tail does
-impl<TargetL, L, T> LookupElemByLabelMutMatch<TargetL, False, True> for Cons<L, T>
+// head doesn't match, look in tail
+impl<TargetL, L, T> LookupElemByLabelMutMatch<TargetL, False> for Cons<L, T>
 where
     L: Label,
     T: LookupElemByLabelMut<TargetL>,
@@ -124,6 +109,238 @@ where
     }
 }
 
+/// Type-level index denoting the head of a list.
+///
+/// Used (along with [There](struct.There.html)) as an inferred index selector by
+/// [LookupValueByType](trait.LookupValueByType.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Here;
+/// Type-level index denoting a position within the tail of a list, where `I` is the index within
+/// the tail.
+///
+/// Used (along with [Here](struct.Here.html)) as an inferred index selector by
+/// [LookupValueByType](trait.LookupValueByType.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct There<I>(PhantomData<I>);
+
+/// Lookup a specific value in a list by its type.
+///
+/// `Index` is a type-level index ([Here](struct.Here.html) or [There](struct.There.html)) which is
+/// typically left for the compiler to infer. If no element contains a value of type `T`, no
+/// implementation exists; if more than one element contains a value of type `T`, the index cannot
+/// be inferred. Both cases fail to compile.
+pub trait LookupValueByType<T, Index> {
+    /// Returns a reference to the value of type `T` from the list
+    fn value_by_type(&self) -> &T;
+}
+
+// head matches
+impl<T, L, Tail> LookupValueByType<T, Here> for Cons<L, Tail>
+where
+    L: Value<Output = T>,
+{
+    fn value_by_type(&self) -> &T {
+        self.head.value_ref()
+    }
+}
+
+// look in tail
+impl<T, L, Tail, TailIndex> LookupValueByType<T, There<TailIndex>> for Cons<L, Tail>
+where
+    Tail: LookupValueByType<T, TailIndex>,
+{
+    fn value_by_type(&self) -> &T {
+        self.tail.value_by_type()
+    }
+}
+
+/// Lookup a specific mutable value in a list by its type.
+///
+/// See [LookupValueByType](trait.LookupValueByType.html) for details on the `Index` parameter.
+pub trait LookupValueByTypeMut<T, Index>: LookupValueByType<T, Index> {
+    /// Returns a mutable reference to the value of type `T` from the list
+    fn value_by_type_mut(&mut self) -> &mut T;
+}
+
+// head matches
+impl<T, L, Tail> LookupValueByTypeMut<T, Here> for Cons<L, Tail>
+where
+    L: Value<Output = T>,
+{
+    fn value_by_type_mut(&mut self) -> &mut T {
+        self.head.value_mut()
+    }
+}
+
+// look in tail
+impl<T, L, Tail, TailIndex> LookupValueByTypeMut<T, There<TailIndex>> for Cons<L, Tail>
+where
+    Tail: LookupValueByTypeMut<T, TailIndex>,
+{
+    fn value_by_type_mut(&mut self) -> &mut T {
+        self.tail.value_by_type_mut()
+    }
+}
+
+/// Lookup a specific element in a list by its position.
+///
+/// `N` is a [typenum](https://docs.rs/typenum) unsigned integer (e.g. `typenum::U2`) denoting the
+/// zero-based position of the element in the list.
+pub trait At<N> {
+    /// The type of the returned element
+    type Output;
+    /// Returns a reference to the element at position `N`
+    fn at(&self) -> &Self::Output;
+    /// Returns a mutable reference to the element at position `N`
+    fn at_mut(&mut self) -> &mut Self::Output;
+}
+
+impl<H, T> At<UTerm> for Cons<H, T> {
+    type Output = H;
+
+    fn at(&self) -> &Self::Output {
+        &self.head
+    }
+    fn at_mut(&mut self) -> &mut Self::Output {
+        &mut self.head
+    }
+}
+
+impl<H, T, U, B> At<UInt<U, B>> for Cons<H, T>
+where
+    UInt<U, B>: Sub<B1>,
+    T: At<Sub1<UInt<U, B>>>,
+{
+    type Output = <T as At<Sub1<UInt<U, B>>>>::Output;
+
+    fn at(&self) -> &Self::Output {
+        At::<Sub1<UInt<U, B>>>::at(&self.tail)
+    }
+    fn at_mut(&mut self) -> &mut Self::Output {
+        At::<Sub1<UInt<U, B>>>::at_mut(&mut self.tail)
+    }
+}
+
+/// Computes the position of a label within a list.
+///
+/// The position is provided as a [typenum](https://docs.rs/typenum) unsigned integer, so that it
+/// can be used for positional access (see [At](trait.At.html)). This trait is not implemented for
+/// lists which do not contain `TargetL`.
+///
+/// ## Example
+/// ```
+/// # #[macro_use] extern crate lhlist;
+/// use lhlist::IndexOf;
+/// use typenum::Unsigned;
+/// # fn main() {
+/// new_label![Label1: u8];
+/// new_label![Label2: bool];
+/// new_label![Label3: &'static str];
+///
+/// type MyLabels = Labels![Label1, Label2, Label3];
+/// assert_eq!(<MyLabels as IndexOf<Label2>>::Output::to_usize(), 1);
+///
+/// // mix label-based and positional access
+/// let list = lhlist![Label1 = 4, Label2 = false, Label3 = "foo"];
+/// type Idx = <MyLabels as IndexOf<Label3>>::Output;
+/// assert_eq!(list.at::<Idx>(), list.elem::<Label3>());
+/// # }
+/// ```
+pub trait IndexOf<TargetL> {
+    /// Position of `TargetL` in this list
+    type Output: Unsigned;
+}
+
+impl<TargetL, L, T> IndexOf<TargetL> for Cons<L, T>
+where
+    L: Label + LabelEq<TargetL>,
+    Self: IndexOfMatch<TargetL, <L as LabelEq<TargetL>>::Output>,
+{
+    type Output = <Self as IndexOfMatch<TargetL, <L as LabelEq<TargetL>>::Output>>::Output;
+}
+
+/// Helper trait for [IndexOf](trait.IndexOf.html).
+pub trait IndexOfMatch<TargetL, HeadMatch> {
+    /// Position of `TargetL` in this list
+    type Output: Unsigned;
+}
+
+impl<TargetL, L, T> IndexOfMatch<TargetL, True> for Cons<L, T> {
+    type Output = UTerm;
+}
+impl<TargetL, L, T> IndexOfMatch<TargetL, False> for Cons<L, T>
+where
+    T: IndexOf<TargetL>,
+    <T as IndexOf<TargetL>>::Output: Add<B1>,
+    Add1<<T as IndexOf<TargetL>>::Output>: Unsigned,
+{
+    type Output = Add1<<T as IndexOf<TargetL>>::Output>;
+}
+
+/// Removes a specific element from a list by label, returning the element along with the
+/// remaining list.
+pub trait Pluck<TargetL> {
+    /// The type of the removed element
+    type Elem;
+    /// The type of the list remaining after the element is removed
+    type Remainder;
+    /// Removes the element from the list, returning it along with the remaining list
+    fn pluck(self) -> (Self::Elem, Self::Remainder);
+}
+
+impl<TargetL, L, T> Pluck<TargetL> for Cons<L, T>
+where
+    L: Label + LabelEq<TargetL>,
+    Self: PluckMatch<TargetL, <L as LabelEq<TargetL>>::Output>,
+{
+    type Elem = <Self as PluckMatch<TargetL, <L as LabelEq<TargetL>>::Output>>::Elem;
+    type Remainder = <Self as PluckMatch<TargetL, <L as LabelEq<TargetL>>::Output>>::Remainder;
+
+    fn pluck(self) -> (Self::Elem, Self::Remainder) {
+        PluckMatch::<TargetL, <L as LabelEq<TargetL>>::Output>::pluck(self)
+    }
+}
+
+/// Helper trait for [Pluck](trait.Pluck.html).
+pub trait PluckMatch<TargetL, HeadMatch> {
+    /// The type of the removed element
+    type Elem;
+    /// The type of the list remaining after the element is removed
+    type Remainder;
+    /// Removes the element from the list, returning it along with the remaining list
+    fn pluck(self) -> (Self::Elem, Self::Remainder);
+}
+
+// head matches
+impl<TargetL, L, T> PluckMatch<TargetL, True> for Cons<L, T> {
+    type Elem = L;
+    type Remainder = T;
+
+    fn pluck(self) -> (Self::Elem, Self::Remainder) {
+        (self.head, self.tail)
+    }
+}
+
+// head doesn't match, look in tail
+impl<TargetL, L, T> PluckMatch<TargetL, False> for Cons<L, T>
+where
+    T: Pluck<TargetL>,
+{
+    type Elem = <T as Pluck<TargetL>>::Elem;
+    type Remainder = Cons<L, <T as Pluck<TargetL>>::Remainder>;
+
+    fn pluck(self) -> (Self::Elem, Self::Remainder) {
+        let (elem, remainder) = self.tail.pluck();
+        (
+            elem,
+            Cons {
+                head: self.head,
+                tail: remainder,
+            },
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::*;
@@ -148,4 +365,74 @@ mod tests {
         println!("{:?}", LookupElemByLabel::<Label2>::elem(&list));
         println!("{:?}", LookupElemByLabel::<Label3>::elem(&list));
     }
+
+    #[test]
+    fn lookup_by_type() {
+        let mut list = lhlist![
+            Label1 = "first value".to_string(),
+            Label2 = 2,
+            Label3 = "third value",
+        ];
+        assert_eq!(list.get_by_type::<String, _>(), "first value");
+        assert_eq!(list.get_by_type::<u8, _>(), &2);
+        assert_eq!(list.get_by_type::<&str, _>(), &"third value");
+
+        *list.get_by_type_mut::<u8, _>() = 5;
+        list.get_by_type_mut::<String, _>().push('!');
+        assert_eq!(
+            list,
+            lhlist![
+                Label1 = "first value!".to_string(),
+                Label2 = 5,
+                Label3 = "third value",
+            ]
+        );
+
+        let value: &u8 = list.get_by_type();
+        assert_eq!(value, &5);
+    }
+
+    #[test]
+    fn lookup_by_position() {
+        use typenum::{U0, U1, U2};
+
+        let mut list = cons![1u8, "two", 3.0f64];
+        assert_eq!(list.at::<U0>(), &1);
+        assert_eq!(list.at::<U1>(), &"two");
+        assert_eq!(list.at::<U2>(), &3.0);
+        *list.at_mut::<U1>() = "deux";
+        assert_eq!(list, cons![1u8, "deux", 3.0f64]);
+
+        let list = lhlist![
+            Label1 = "first value".to_string(),
+            Label2 = 2,
+            Label3 = "third value",
+        ];
+        type Idx2 = <Labels![Label1, Label2, Label3] as IndexOf<Label2>>::Output;
+        assert_eq!(list.at::<Idx2>(), list.elem::<Label2>());
+        assert_eq!(list.index_of::<Label1>(), 0);
+        assert_eq!(list.index_of::<Label2>(), 1);
+        assert_eq!(list.index_of::<Label3>(), 2);
+    }
+
+    #[test]
+    fn pluck() {
+        let list = lhlist![
+            Label1 = "first value".to_string(),
+            Label2 = 2,
+            Label3 = "third value",
+        ];
+        let (elem, rest) = list.clone().pluck::<Label2>();
+        assert_eq!(elem, labeled(Label2, 2));
+        assert_eq!(
+            rest,
+            lhlist![Label1 = "first value".to_string(), Label3 = "third value"]
+        );
+
+        let (elem, rest) = list.pluck::<Label1>();
+        assert_eq!(elem, labeled(Label1, "first value".to_string()));
+        let (elem, rest) = rest.pluck::<Label3>();
+        assert_eq!(elem, labeled(Label3, "third value"));
+        assert_eq!(rest, lhlist![Label2 = 2]);
+    }
 }
@@ -0,0 +1,460 @@
+use crate::cons::{Cons, LCons, LVCons, Nil};
+use crate::label::{Label, Value};
+use crate::lookup::{LookupElemByLabel, LookupElemByLabelMut};
+use crate::relation::{Bool, False, True};
+
+/// Trait for labels which specify whether their values are nested label-value cons-lists.
+///
+/// Labels created with the [label](attr.label.html) attribute are nested if they specify the
+/// `nested` option, in which case their associated type must be a label-value cons-list. Nested
+/// values are spliced into their parent list by [flatten](struct.Cons.html#method.flatten), and
+/// their labels are prefixed with the nested label's name by
+/// [flat_labels](trait.FlatLabels.html#method.flat_labels).
+///
+/// ## Example
+/// ```
+/// # #[macro_use] extern crate lhlist;
+/// use lhlist::FlatLabels;
+/// # fn main() {
+/// #[label(name="city", type=&'static str)]
+/// struct City;
+/// #[label(name="zip", type=u32)]
+/// struct Zip;
+/// #[label(name="name", type=&'static str)]
+/// struct Name;
+/// #[label(name="address", type=LHList![City, Zip], nested)]
+/// struct Address;
+///
+/// let customer = lhlist![
+///     Name = "Jane",
+///     Address = lhlist![City = "Springfield", Zip = 12345],
+/// ];
+/// assert_eq!(customer.flat_labels(), vec!["name", "address.city", "address.zip"]);
+/// assert_eq!(customer.path::<(Address, City)>(), &"Springfield");
+///
+/// let flat = customer.flatten();
+/// assert_eq!(flat, lhlist![Name = "Jane", City = "Springfield", Zip = 12345]);
+/// # }
+/// ```
+pub trait LabelNested: Label {
+    /// Whether this label's values are nested label-value cons-lists
+    type Nested: Bool;
+}
+
+/// Appends a list to the end of another list.
+pub trait Append<Other> {
+    /// The combined list
+    type Output;
+    /// Appends `other` to the end of this list
+    fn append(self, other: Other) -> Self::Output;
+}
+
+impl<Other> Append<Other> for Nil {
+    type Output = Other;
+
+    fn append(self, other: Other) -> Other {
+        other
+    }
+}
+
+impl<Other, H, T> Append<Other> for Cons<H, T>
+where
+    T: Append<Other>,
+{
+    type Output = Cons<H, <T as Append<Other>>::Output>;
+
+    fn append(self, other: Other) -> Self::Output {
+        Cons {
+            head: self.head,
+            tail: self.tail.append(other),
+        }
+    }
+}
+
+/// Flattens a label-value cons-list by splicing the values of [nested](trait.LabelNested.html)
+/// labels into the list, in place of the nested label's element. Flattening is recursive, so
+/// nested lists within nested lists are also flattened.
+pub trait Flatten {
+    /// The flattened list
+    type Output;
+    /// Flattens this list
+    fn flatten(self) -> Self::Output;
+}
+
+impl Flatten for Nil {
+    type Output = Nil;
+
+    fn flatten(self) -> Nil {
+        Nil
+    }
+}
+
+impl<L, T> Flatten for LVCons<L, T>
+where
+    L: LabelNested,
+    Self: FlattenMatch<L::Nested>,
+{
+    type Output = <Self as FlattenMatch<L::Nested>>::Output;
+
+    fn flatten(self) -> Self::Output {
+        FlattenMatch::<L::Nested>::flatten(self)
+    }
+}
+
+/// Helper trait for [Flatten](trait.Flatten.html).
+pub trait FlattenMatch<Nested> {
+    /// The flattened list
+    type Output;
+    /// Flattens this list
+    fn flatten(self) -> Self::Output;
+}
+
+// head isn't nested
+impl<L, T> FlattenMatch<False> for LVCons<L, T>
+where
+    L: Label,
+    T: Flatten,
+{
+    type Output = LVCons<L, <T as Flatten>::Output>;
+
+    fn flatten(self) -> Self::Output {
+        Cons {
+            head: self.head,
+            tail: Flatten::flatten(self.tail),
+        }
+    }
+}
+
+// head is nested, splice its flattened value into the list
+impl<L, T> FlattenMatch<True> for LVCons<L, T>
+where
+    L: Label,
+    L::AssocType: Flatten,
+    T: Flatten,
+    <L::AssocType as Flatten>::Output: Append<<T as Flatten>::Output>,
+{
+    type Output = <<L::AssocType as Flatten>::Output as Append<<T as Flatten>::Output>>::Output;
+
+    fn flatten(self) -> Self::Output {
+        Flatten::flatten(self.head.value).append(Flatten::flatten(self.tail))
+    }
+}
+
+/// Generate a `Vec` containing the path-qualified names of the labels in a label-value cons-list,
+/// in the order produced by [flatten](trait.Flatten.html).
+///
+/// The labels of the values of [nested](trait.LabelNested.html) labels are prefixed by the nested
+/// label's name, separated by a period (e.g. `customer.address.city`).
+pub trait FlatLabels {
+    /// Generates the path-qualified label name `Vec`
+    fn static_flat_labels() -> Vec<String> {
+        let mut output = vec![];
+        Self::build_flat_labels(&mut output, "");
+        output
+    }
+    /// Generates the path-qualified label name `Vec` using a value
+    fn flat_labels(&self) -> Vec<String> {
+        Self::static_flat_labels()
+    }
+    /// Adds the path-qualified label names of this list to `v`, prefixing each with `prefix`
+    fn build_flat_labels(v: &mut Vec<String>, prefix: &str);
+}
+
+impl FlatLabels for Nil {
+    fn build_flat_labels(_v: &mut Vec<String>, _prefix: &str) {}
+}
+
+impl<L, T> FlatLabels for LVCons<L, T>
+where
+    L: LabelNested,
+    Self: FlatLabelsMatch<L::Nested>,
+    T: FlatLabels,
+{
+    fn build_flat_labels(v: &mut Vec<String>, prefix: &str) {
+        <Self as FlatLabelsMatch<L::Nested>>::build_head_labels(v, prefix);
+        T::build_flat_labels(v, prefix);
+    }
+}
+
+/// Helper trait for [FlatLabels](trait.FlatLabels.html).
+pub trait FlatLabelsMatch<Nested> {
+    /// Adds the path-qualified label names of the head of this list to `v`
+    fn build_head_labels(v: &mut Vec<String>, prefix: &str);
+}
+
+impl<L, T> FlatLabelsMatch<False> for LVCons<L, T>
+where
+    L: Label,
+{
+    fn build_head_labels(v: &mut Vec<String>, prefix: &str) {
+        v.push(format!("{}{}", prefix, L::NAME));
+    }
+}
+
+impl<L, T> FlatLabelsMatch<True> for LVCons<L, T>
+where
+    L: Label,
+    L::AssocType: FlatLabels,
+{
+    fn build_head_labels(v: &mut Vec<String>, prefix: &str) {
+        let prefix = format!("{}{}.", prefix, L::NAME);
+        <L::AssocType as FlatLabels>::build_flat_labels(v, &prefix);
+    }
+}
+
+/// Lookup a value within nested label-value cons-lists by a path of labels.
+///
+/// `P` is a tuple of labels (e.g. `(Customer, Address, City)`) or an [LCons](type.LCons.html)
+/// label-only cons-list (e.g. `Labels![Customer, Address, City]`). The first label is looked up in
+/// this list, and each following label is looked up in the value found by the previous label.
+pub trait Path<P> {
+    /// The type of the value at the end of the path
+    type Output: ?Sized;
+    /// Returns a reference to the value at the end of the path
+    fn path<'a>(&'a self) -> &'a Self::Output
+    where
+        P: 'a;
+}
+
+/// Lookup a mutable value within nested label-value cons-lists by a path of labels.
+///
+/// See [Path](trait.Path.html) for more details.
+pub trait PathMut<P>: Path<P> {
+    /// Returns a mutable reference to the value at the end of the path
+    fn path_mut<'a>(&'a mut self) -> &'a mut Self::Output
+    where
+        P: 'a;
+}
+
+macro_rules! impl_path {
+    ($first:ident) => {
+        impl<List, $first> Path<($first,)> for List
+        where
+            List: LookupElemByLabel<$first>,
+            List::Elem: Value,
+        {
+            type Output = <List::Elem as Value>::Output;
+
+            fn path<'a>(&'a self) -> &'a Self::Output
+            where
+                ($first,): 'a,
+            {
+                self.elem().value_ref()
+            }
+        }
+
+        impl<List, $first> PathMut<($first,)> for List
+        where
+            List: LookupElemByLabelMut<$first>,
+            List::Elem: Value,
+        {
+            fn path_mut<'a>(&'a mut self) -> &'a mut Self::Output
+            where
+                ($first,): 'a,
+            {
+                self.elem_mut().value_mut()
+            }
+        }
+    };
+    ($first:ident, $($rest:ident),*) => {
+        impl<List, $first, $($rest),*> Path<($first, $($rest),*)> for List
+        where
+            List: LookupElemByLabel<$first>,
+            List::Elem: Value,
+            <List::Elem as Value>::Output: Path<($($rest,)*)>,
+        {
+            type Output = <<List::Elem as Value>::Output as Path<($($rest,)*)>>::Output;
+
+            fn path<'a>(&'a self) -> &'a Self::Output
+            where
+                ($first, $($rest),*): 'a,
+            {
+                self.elem().value_ref().path()
+            }
+        }
+
+        impl<List, $first, $($rest),*> PathMut<($first, $($rest),*)> for List
+        where
+            List: LookupElemByLabelMut<$first>,
+            List::Elem: Value,
+            <List::Elem as Value>::Output: PathMut<($($rest,)*)>,
+        {
+            fn path_mut<'a>(&'a mut self) -> &'a mut Self::Output
+            where
+                ($first, $($rest),*): 'a,
+            {
+                self.elem_mut().value_mut().path_mut()
+            }
+        }
+
+        impl_path!($($rest),*);
+    };
+}
+impl_path!(L1, L2, L3, L4, L5, L6, L7, L8);
+
+impl<List, L> Path<LCons<L, Nil>> for List
+where
+    List: LookupElemByLabel<L>,
+    List::Elem: Value,
+{
+    type Output = <List::Elem as Value>::Output;
+
+    fn path<'a>(&'a self) -> &'a Self::Output
+    where
+        LCons<L, Nil>: 'a,
+    {
+        self.elem().value_ref()
+    }
+}
+
+impl<List, L, NextL, NextT> Path<LCons<L, LCons<NextL, NextT>>> for List
+where
+    List: LookupElemByLabel<L>,
+    List::Elem: Value,
+    <List::Elem as Value>::Output: Path<LCons<NextL, NextT>>,
+{
+    type Output = <<List::Elem as Value>::Output as Path<LCons<NextL, NextT>>>::Output;
+
+    fn path<'a>(&'a self) -> &'a Self::Output
+    where
+        LCons<L, LCons<NextL, NextT>>: 'a,
+    {
+        self.elem().value_ref().path()
+    }
+}
+
+impl<List, L> PathMut<LCons<L, Nil>> for List
+where
+    List: LookupElemByLabelMut<L>,
+    List::Elem: Value,
+{
+    fn path_mut<'a>(&'a mut self) -> &'a mut Self::Output
+    where
+        LCons<L, Nil>: 'a,
+    {
+        self.elem_mut().value_mut()
+    }
+}
+
+impl<List, L, NextL, NextT> PathMut<LCons<L, LCons<NextL, NextT>>> for List
+where
+    List: LookupElemByLabelMut<L>,
+    List::Elem: Value,
+    <List::Elem as Value>::Output: PathMut<LCons<NextL, NextT>>,
+{
+    fn path_mut<'a>(&'a mut self) -> &'a mut Self::Output
+    where
+        LCons<L, LCons<NextL, NextT>>: 'a,
+    {
+        self.elem_mut().value_mut().path_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[label(name="city", type=String, crate=crate)]
+    struct City;
+    #[label(name="zip", type=u32, crate=crate)]
+    struct Zip;
+    #[label(name="address", type=LHList![City, Zip], nested, crate=crate)]
+    struct Address;
+    #[label(name="name", type=String, crate=crate)]
+    struct Name;
+    #[label(name="customer", type=LHList![Name, Address], nested, crate=crate)]
+    struct Customer;
+    #[label(name="id", type=u64, crate=crate)]
+    struct Id;
+
+    fn order() -> LHList![Id, Customer] {
+        lhlist![
+            Id = 7,
+            Customer = lhlist![
+                Name = "Jane".to_string(),
+                Address = lhlist![City = "Springfield".to_string(), Zip = 12345],
+            ],
+        ]
+    }
+
+    #[test]
+    fn flatten() {
+        let flat = order().flatten();
+        assert_eq!(flat.labels(), vec!["id", "name", "city", "zip"]);
+        assert_eq!(
+            flat,
+            lhlist![
+                Id = 7,
+                Name = "Jane".to_string(),
+                City = "Springfield".to_string(),
+                Zip = 12345
+            ]
+        );
+        assert_eq!(Nil.flatten(), Nil);
+    }
+
+    #[test]
+    fn flat_labels() {
+        let order = order();
+        assert_eq!(
+            order.flat_labels(),
+            vec![
+                "id",
+                "customer.name",
+                "customer.address.city",
+                "customer.address.zip"
+            ]
+        );
+        assert_eq!(
+            <LHList![Address, Id]>::static_flat_labels(),
+            vec!["address.city", "address.zip", "id"]
+        );
+        assert_eq!(
+            order.flatten().flat_labels(),
+            vec!["id", "name", "city", "zip"]
+        );
+    }
+
+    #[test]
+    fn path() {
+        let mut order = order();
+        assert_eq!(order.path::<(Id,)>(), &7);
+        assert_eq!(order.path::<(Customer, Name)>(), "Jane");
+        assert_eq!(order.path::<(Customer, Address, Zip)>(), &12345);
+
+        order
+            .path_mut::<(Customer, Address, City)>()
+            .push_str(", IL");
+        assert_eq!(order.path::<(Customer, Address, City)>(), "Springfield, IL");
+        assert_eq!(order[Customer][Address][City], "Springfield, IL");
+
+        assert_eq!(order.path::<Labels![Customer, Address, Zip]>(), &12345);
+        *order.path_mut::<Labels![Id]>() += 1;
+        assert_eq!(order[Id], 8);
+    }
+
+    #[label(name="note", type=Option<String>, crate=crate)]
+    struct Note;
+
+    #[test]
+    fn derived_labels() {
+        // derived labels are never nested
+        let order = order();
+        let refs = order.as_refs();
+        assert_eq!(refs.flat_labels(), vec!["id", "customer"]);
+        let flat = refs.flatten();
+        assert_eq!(flat.labels(), vec!["id", "customer"]);
+        assert_eq!(flat.value::<Customer>()[Name], "Jane");
+
+        let mut partial = Partial::<Labels![Id, Customer]>::new();
+        partial.set::<Id>(7);
+        assert_eq!(partial.fields().flat_labels(), vec!["id", "customer"]);
+        assert_eq!(partial.fields().clone().flatten().value::<Id>(), &Some(7));
+
+        let list = lhlist![Note = Some("fragile".to_string())]
+            .transpose_option()
+            .unwrap();
+        assert_eq!(list.flat_labels(), vec!["note"]);
+        assert_eq!(list.flatten().value::<Note>(), "fragile");
+    }
+}
@@ -0,0 +1,346 @@
+use std::marker::PhantomData;
+
+use crate::cons::{Cons, LCons, LVCons, Nil};
+use crate::defaults::LabelDefault;
+use crate::dynamic::LabelFromStr;
+use crate::label::{Label, LabeledValue, Value};
+use crate::lookup::{LookupElemByLabel, LookupElemByLabelMut};
+use crate::validate::LabelValidate;
+
+/// Label derived from label `L`, with associated type `Option<L::AssocType>`.
+///
+/// `Optional<L>` has the same name and identifier as `L`, so elements labeled with `Optional<L>`
+/// can still be accessed using `L`. Used for the fields of a [Partial](struct.Partial.html) record.
+pub struct Optional<L>(PhantomData<L>);
+
+impl<L> Label for Optional<L>
+where
+    L: Label,
+{
+    const NAME: &'static str = L::NAME;
+    type AssocType = Option<L::AssocType>;
+    type Uid = L::Uid;
+}
+
+impl<L> LabelDefault for Optional<L>
+where
+    L: Label,
+{
+    fn default_value() -> Option<L::AssocType> {
+        None
+    }
+}
+
+impl<L> LabelValidate for Optional<L>
+where
+    L: LabelValidate,
+{
+    fn validate(value: &Option<L::AssocType>) -> Result<(), String> {
+        match value {
+            Some(value) => L::validate(value),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<L> LabelFromStr for Optional<L>
+where
+    L: LabelFromStr,
+{
+    fn parse_value(s: &str) -> Option<Result<Option<L::AssocType>, String>> {
+        L::parse_value(s).map(|value| value.map(Some))
+    }
+}
+
+impl_label_traits!(Optional<L>);
+
+/// A record of labels `Labels` (an [LCons](type.LCons.html) label-only cons-list, typically created
+/// with the [Labels](macro.Labels.html) macro) in which each value may or may not be set yet.
+///
+/// Values are stored in a cons-list whose values are `Option<L::AssocType>` for each label `L` in
+/// `Labels`. Once all values are set, the partial record can be converted to a full
+/// [LVCons](type.LVCons.html) label-value cons-list with [complete](#method.complete).
+///
+/// ## Example
+/// ```
+/// # #[macro_use] extern crate lhlist;
+/// use lhlist::Partial;
+/// # fn main() {
+/// new_label![Name: String];
+/// new_label![Age: u8];
+/// new_label![Email: String];
+///
+/// type Person = Labels![Name, Age, Email];
+///
+/// let mut from_form = Partial::<Person>::new();
+/// from_form.set::<Name>("Jane".to_string());
+/// let mut from_db = Partial::<Person>::new();
+/// from_db.set::<Age>(37);
+///
+/// let partial = from_form.merge(from_db);
+/// assert!(!partial.is_complete());
+/// assert_eq!(partial.get::<Age>(), Some(&37));
+/// assert_eq!(partial.clone().complete().unwrap_err(), vec!["Email"]);
+///
+/// let mut partial = partial;
+/// partial.set::<Email>("jane@example.com".to_string());
+/// let person = partial.complete().unwrap();
+/// assert_eq!(person[Name], "Jane");
+/// assert_eq!(person[Age], 37);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Partial<Labels>
+where
+    Labels: PartialLabels,
+{
+    fields: Labels::Fields,
+}
+
+impl<Labels> Partial<Labels>
+where
+    Labels: PartialLabels,
+{
+    /// Creates a new partial record, with no values set.
+    pub fn new() -> Self {
+        Partial {
+            fields: Labels::empty(),
+        }
+    }
+
+    /// Returns a reference to the cons-list of optional values.
+    pub fn fields(&self) -> &Labels::Fields {
+        &self.fields
+    }
+
+    /// Sets the value for a specific label, replacing any existing value.
+    pub fn set<TargetL>(
+        &mut self,
+        value: <Labels::Fields as PartialField<TargetL>>::Value,
+    ) -> &mut Self
+    where
+        Labels::Fields: PartialField<TargetL>,
+    {
+        *self.fields.field_mut() = Some(value);
+        self
+    }
+
+    /// Returns a reference to the value for a specific label, if set.
+    pub fn get<'a, TargetL>(
+        &'a self,
+    ) -> Option<&'a <Labels::Fields as PartialField<TargetL>>::Value>
+    where
+        Labels::Fields: PartialField<TargetL>,
+        TargetL: 'a,
+    {
+        self.fields.field().as_ref()
+    }
+
+    /// Merges two partial records of the same labels. Values set in `other` replace values in
+    /// `self`.
+    pub fn merge(self, other: Self) -> Self {
+        Partial {
+            fields: Labels::merge(self.fields, other.fields),
+        }
+    }
+
+    /// Returns `true` if the values for all labels are set.
+    pub fn is_complete(&self) -> bool {
+        let mut missing = vec![];
+        Labels::missing(&self.fields, &mut missing);
+        missing.is_empty()
+    }
+
+    /// Converts this partial record into a label-value cons-list if the values for all labels are
+    /// set. Otherwise, returns the names of the labels whose values are missing, in list order.
+    pub fn complete(self) -> Result<Labels::Complete, Vec<&'static str>> {
+        let mut missing = vec![];
+        match Labels::complete(self.fields, &mut missing) {
+            Some(complete) => Ok(complete),
+            None => Err(missing),
+        }
+    }
+}
+
+impl<Labels> Default for Partial<Labels>
+where
+    Labels: PartialLabels,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Trait for label-only cons-lists which can be used as the labels of a
+/// [Partial](struct.Partial.html) record.
+pub trait PartialLabels {
+    /// Cons-list of optional values for these labels
+    type Fields;
+    /// Label-value cons-list with values for all of these labels
+    type Complete;
+
+    /// Creates a cons-list of optional values with no values set
+    fn empty() -> Self::Fields;
+    /// Merges two cons-lists of optional values, preferring the values in `other`
+    fn merge(fields: Self::Fields, other: Self::Fields) -> Self::Fields;
+    /// Adds the names of labels without values to `missing`
+    fn missing(fields: &Self::Fields, missing: &mut Vec<&'static str>);
+    /// Returns the full label-value cons-list if all values are set, adding the names of labels
+    /// without values to `missing` otherwise
+    fn complete(fields: Self::Fields, missing: &mut Vec<&'static str>) -> Option<Self::Complete>;
+}
+
+impl PartialLabels for Nil {
+    type Fields = Nil;
+    type Complete = Nil;
+
+    fn empty() -> Nil {
+        Nil
+    }
+    fn merge(_fields: Nil, _other: Nil) -> Nil {
+        Nil
+    }
+    fn missing(_fields: &Nil, _missing: &mut Vec<&'static str>) {}
+    fn complete(_fields: Nil, _missing: &mut Vec<&'static str>) -> Option<Nil> {
+        Some(Nil)
+    }
+}
+
+impl<L, T> PartialLabels for LCons<L, T>
+where
+    L: Label,
+    T: PartialLabels,
+{
+    type Fields = LVCons<Optional<L>, T::Fields>;
+    type Complete = LVCons<L, T::Complete>;
+
+    fn empty() -> Self::Fields {
+        Cons {
+            head: LabeledValue::new(None),
+            tail: T::empty(),
+        }
+    }
+    fn merge(fields: Self::Fields, other: Self::Fields) -> Self::Fields {
+        Cons {
+            head: LabeledValue::new(other.head.value.or(fields.head.value)),
+            tail: T::merge(fields.tail, other.tail),
+        }
+    }
+    fn missing(fields: &Self::Fields, missing: &mut Vec<&'static str>) {
+        if fields.head.value.is_none() {
+            missing.push(L::NAME);
+        }
+        T::missing(&fields.tail, missing);
+    }
+    fn complete(fields: Self::Fields, missing: &mut Vec<&'static str>) -> Option<Self::Complete> {
+        if fields.head.value.is_none() {
+            missing.push(L::NAME);
+        }
+        let tail = T::complete(fields.tail, missing);
+        Some(Cons {
+            head: LabeledValue::new(fields.head.value?),
+            tail: tail?,
+        })
+    }
+}
+
+/// Access to the optional value for a specific label in the fields of a
+/// [Partial](struct.Partial.html) record.
+pub trait PartialField<TargetL> {
+    /// The type of the value (when set)
+    type Value;
+
+    /// Returns a reference to the optional value
+    fn field<'a>(&'a self) -> &'a Option<Self::Value>
+    where
+        TargetL: 'a;
+    /// Returns a mutable reference to the optional value
+    fn field_mut<'a>(&'a mut self) -> &'a mut Option<Self::Value>
+    where
+        TargetL: 'a;
+}
+
+impl<TargetL, Fields, V> PartialField<TargetL> for Fields
+where
+    Fields: LookupElemByLabelMut<TargetL>,
+    <Fields as LookupElemByLabel<TargetL>>::Elem: Value<Output = Option<V>>,
+{
+    type Value = V;
+
+    fn field<'a>(&'a self) -> &'a Option<V>
+    where
+        TargetL: 'a,
+    {
+        self.elem().value_ref()
+    }
+    fn field_mut<'a>(&'a mut self) -> &'a mut Option<V>
+    where
+        TargetL: 'a,
+    {
+        self.elem_mut().value_mut()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[label(type=u8, crate=crate)]
+    struct Label1;
+    #[label(type=String, crate=crate)]
+    struct Label2;
+    #[label(type=bool, crate=crate)]
+    struct Label3;
+
+    type TestLabels = Labels![Label1, Label2, Label3];
+
+    #[test]
+    fn set_complete() {
+        let mut partial = Partial::<TestLabels>::new();
+        assert!(!partial.is_complete());
+        assert_eq!(
+            partial.clone().complete(),
+            Err(vec!["Label1", "Label2", "Label3"])
+        );
+
+        partial.set::<Label2>("foo".to_string()).set::<Label1>(4);
+        assert_eq!(partial.get::<Label1>(), Some(&4));
+        assert_eq!(partial.get::<Label3>(), None);
+        assert_eq!(partial.clone().complete(), Err(vec!["Label3"]));
+
+        partial.set::<Label3>(true).set::<Label1>(5);
+        assert!(partial.is_complete());
+        assert_eq!(
+            partial.complete(),
+            Ok(lhlist![
+                Label1 = 5,
+                Label2 = "foo".to_string(),
+                Label3 = true
+            ])
+        );
+    }
+
+    #[test]
+    fn merge() {
+        let mut first = Partial::<TestLabels>::new();
+        first.set::<Label1>(4).set::<Label2>("foo".to_string());
+        let mut second = Partial::<TestLabels>::new();
+        second.set::<Label2>("bar".to_string()).set::<Label3>(false);
+
+        let merged = first.clone().merge(second.clone());
+        assert_eq!(
+            merged.complete(),
+            Ok(lhlist![
+                Label1 = 4,
+                Label2 = "bar".to_string(),
+                Label3 = false
+            ])
+        );
+        let merged = second.merge(first);
+        assert_eq!(merged.get::<Label2>(), Some(&"foo".to_string()));
+        assert!(merged.is_complete());
+
+        let empty = Partial::<TestLabels>::default();
+        assert_eq!(empty.clone().merge(empty.clone()), empty);
+    }
+}
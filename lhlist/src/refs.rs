@@ -0,0 +1,279 @@
+use std::marker::PhantomData;
+
+use crate::cons::{Cons, LVCons, Nil};
+use crate::label::{Label, LabeledValue};
+use crate::validate::LabelValidate;
+
+/// Label derived from label `L`, with associated type `&'a L::AssocType`.
+///
+/// `Ref<'a, L>` has the same name and identifier as `L`, so elements labeled with `Ref<'a, L>` can
+/// still be accessed using `L`. Used for the lists created by
+/// [as_refs](trait.AsRefs.html#tymethod.as_refs).
+pub struct Ref<'a, L>(PhantomData<&'a L>);
+
+/// Label derived from label `L`, with associated type `&'a mut L::AssocType`.
+///
+/// `RefMut<'a, L>` has the same name and identifier as `L`, so elements labeled with
+/// `RefMut<'a, L>` can still be accessed using `L`. Used for the lists created by
+/// [as_mut_refs](trait.AsRefs.html#tymethod.as_mut_refs).
+pub struct RefMut<'a, L>(PhantomData<&'a mut L>);
+
+impl<'a, L> Label for Ref<'a, L>
+where
+    L: Label,
+    L::AssocType: 'a,
+{
+    const NAME: &'static str = L::NAME;
+    type AssocType = &'a L::AssocType;
+    type Uid = L::Uid;
+}
+
+impl<'a, L> Label for RefMut<'a, L>
+where
+    L: Label,
+    L::AssocType: 'a,
+{
+    const NAME: &'static str = L::NAME;
+    type AssocType = &'a mut L::AssocType;
+    type Uid = L::Uid;
+}
+
+impl<'a, L> LabelValidate for Ref<'a, L>
+where
+    L: LabelValidate,
+    L::AssocType: 'a,
+{
+    fn validate(value: &&'a L::AssocType) -> Result<(), String> {
+        L::validate(value)
+    }
+}
+
+impl<'a, L> LabelValidate for RefMut<'a, L>
+where
+    L: LabelValidate,
+    L::AssocType: 'a,
+{
+    fn validate(value: &&'a mut L::AssocType) -> Result<(), String> {
+        L::validate(value)
+    }
+}
+
+impl_label_traits!(Ref<'a, L>);
+impl_label_traits!(RefMut<'a, L>);
+
+/// Creates views of a label-value cons-list whose values are references to the values of the
+/// original list.
+///
+/// Each label `L` is replaced by [Ref<'a, L>](struct.Ref.html) (or
+/// [RefMut<'a, L>](struct.RefMut.html)), which shares the name and identifier of `L`. Views can be
+/// converted back into owned lists with [cloned](trait.ClonedRefs.html#tymethod.cloned) or
+/// [copied](trait.CopiedRefs.html#tymethod.copied).
+///
+/// ## Example
+/// ```
+/// # #[macro_use] extern crate lhlist;
+/// use lhlist::{AsRefs, ClonedRefs, StrLabels};
+/// # fn main() {
+/// new_label![Name: String];
+/// new_label![Age: u8];
+///
+/// let mut list = lhlist![Name = "Jane".to_string(), Age = 37];
+///
+/// let refs = list.as_refs();
+/// assert_eq!(refs.labels(), vec!["Name", "Age"]);
+/// assert_eq!(*refs.value::<Name>(), "Jane");
+/// let owned = refs.cloned();
+/// assert_eq!(owned, list);
+///
+/// let mut refs = list.as_mut_refs();
+/// **refs.value_mut::<Age>() += 1;
+/// assert_eq!(list[Age], 38);
+/// # }
+/// ```
+pub trait AsRefs<'a> {
+    /// The list of references
+    type Refs;
+    /// The list of mutable references
+    type MutRefs;
+
+    /// Creates a list of references to the values of this list
+    fn as_refs(&'a self) -> Self::Refs;
+    /// Creates a list of mutable references to the values of this list
+    fn as_mut_refs(&'a mut self) -> Self::MutRefs;
+}
+
+impl<'a> AsRefs<'a> for Nil {
+    type Refs = Nil;
+    type MutRefs = Nil;
+
+    fn as_refs(&'a self) -> Nil {
+        Nil
+    }
+    fn as_mut_refs(&'a mut self) -> Nil {
+        Nil
+    }
+}
+
+impl<'a, L, T> AsRefs<'a> for LVCons<L, T>
+where
+    L: Label + 'a,
+    L::AssocType: 'a,
+    T: AsRefs<'a>,
+{
+    type Refs = LVCons<Ref<'a, L>, T::Refs>;
+    type MutRefs = LVCons<RefMut<'a, L>, T::MutRefs>;
+
+    fn as_refs(&'a self) -> Self::Refs {
+        Cons {
+            head: LabeledValue::new(&self.head.value),
+            tail: self.tail.as_refs(),
+        }
+    }
+    fn as_mut_refs(&'a mut self) -> Self::MutRefs {
+        Cons {
+            head: LabeledValue::new(&mut self.head.value),
+            tail: self.tail.as_mut_refs(),
+        }
+    }
+}
+
+/// Creates owned label-value cons-lists by cloning the values of lists of references created by
+/// [as_refs](trait.AsRefs.html#tymethod.as_refs) or
+/// [as_mut_refs](trait.AsRefs.html#tymethod.as_mut_refs).
+///
+/// The resulting list uses the original labels.
+pub trait ClonedRefs {
+    /// The owned list
+    type Owned;
+    /// Creates an owned list by cloning each referenced value
+    fn cloned(&self) -> Self::Owned;
+}
+
+/// Creates owned label-value cons-lists by copying the values of lists of references created by
+/// [as_refs](trait.AsRefs.html#tymethod.as_refs) or
+/// [as_mut_refs](trait.AsRefs.html#tymethod.as_mut_refs).
+///
+/// The resulting list uses the original labels.
+pub trait CopiedRefs: ClonedRefs {
+    /// Creates an owned list by copying each referenced value
+    fn copied(&self) -> Self::Owned;
+}
+
+impl ClonedRefs for Nil {
+    type Owned = Nil;
+
+    fn cloned(&self) -> Nil {
+        Nil
+    }
+}
+impl CopiedRefs for Nil {
+    fn copied(&self) -> Nil {
+        Nil
+    }
+}
+
+impl<'a, L, T> ClonedRefs for LVCons<Ref<'a, L>, T>
+where
+    L: Label,
+    L::AssocType: Clone,
+    T: ClonedRefs,
+{
+    type Owned = LVCons<L, T::Owned>;
+
+    fn cloned(&self) -> Self::Owned {
+        Cons {
+            head: LabeledValue::new(self.head.value.clone()),
+            tail: self.tail.cloned(),
+        }
+    }
+}
+impl<'a, L, T> CopiedRefs for LVCons<Ref<'a, L>, T>
+where
+    L: Label,
+    L::AssocType: Copy,
+    T: CopiedRefs,
+{
+    fn copied(&self) -> Self::Owned {
+        Cons {
+            head: LabeledValue::new(*self.head.value),
+            tail: self.tail.copied(),
+        }
+    }
+}
+
+impl<'a, L, T> ClonedRefs for LVCons<RefMut<'a, L>, T>
+where
+    L: Label,
+    L::AssocType: Clone,
+    T: ClonedRefs,
+{
+    type Owned = LVCons<L, T::Owned>;
+
+    fn cloned(&self) -> Self::Owned {
+        Cons {
+            head: LabeledValue::new((*self.head.value).clone()),
+            tail: self.tail.cloned(),
+        }
+    }
+}
+impl<'a, L, T> CopiedRefs for LVCons<RefMut<'a, L>, T>
+where
+    L: Label,
+    L::AssocType: Copy,
+    T: CopiedRefs,
+{
+    fn copied(&self) -> Self::Owned {
+        Cons {
+            head: LabeledValue::new(*self.head.value),
+            tail: self.tail.copied(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::iter::CollectIntoHList;
+    use crate::*;
+
+    #[label(type=u8, crate=crate)]
+    struct Label1;
+    #[label(type=String, crate=crate)]
+    struct Label2;
+    #[label(name="Third", type=bool, crate=crate)]
+    struct Label3;
+
+    #[test]
+    fn as_refs() {
+        let mut list = lhlist![Label1 = 4, Label2 = "foo".to_string(), Label3 = true];
+
+        let refs = list.as_refs();
+        assert_eq!(refs.labels(), vec!["Label1", "Label2", "Third"]);
+        assert_eq!(refs.value::<Label1>(), &&4);
+        assert!(refs.has_label(Label3));
+        assert_eq!(refs.index_of::<Label3>(), 2);
+        assert_eq!(
+            refs.iter_values().collect_into_hlist(),
+            cons![&&4, &&"foo".to_string(), &&true]
+        );
+        assert_eq!(refs.cloned(), list);
+        let (elem, _) = refs.pluck::<Label2>();
+        assert_eq!(elem.value, "foo");
+
+        let mut refs = list.as_mut_refs();
+        **refs.value_mut::<Label1>() += 1;
+        refs.value_mut::<Label2>().push_str("bar");
+        assert_eq!(refs.cloned().labels(), vec!["Label1", "Label2", "Third"]);
+        assert_eq!(
+            list,
+            lhlist![Label1 = 5, Label2 = "foobar".to_string(), Label3 = true]
+        );
+    }
+
+    #[test]
+    fn copied() {
+        let list = lhlist![Label1 = 4, Label3 = true];
+        let copied = list.as_refs().copied();
+        assert_eq!(copied, list);
+        assert_eq!(Nil.as_refs().copied(), Nil);
+    }
+}
@@ -32,6 +32,86 @@ impl ToBool for B0 {
     type Output = False;
 }
 
+/// Type-level logical conjunction.
+///
+/// `<A as And<B>>::Output` is [True](struct.True.html) if both `A` and `B` are
+/// [True](struct.True.html), and [False](struct.False.html) otherwise.
+pub trait And<Rhs> {
+    /// Result of the conjunction
+    type Output: Bool;
+}
+impl<Rhs: Bool> And<Rhs> for True {
+    type Output = Rhs;
+}
+impl<Rhs: Bool> And<Rhs> for False {
+    type Output = False;
+}
+
+/// Type-level logical disjunction.
+///
+/// `<A as Or<B>>::Output` is [True](struct.True.html) if either `A` or `B` are
+/// [True](struct.True.html), and [False](struct.False.html) otherwise.
+pub trait Or<Rhs> {
+    /// Result of the disjunction
+    type Output: Bool;
+}
+impl<Rhs: Bool> Or<Rhs> for True {
+    type Output = True;
+}
+impl<Rhs: Bool> Or<Rhs> for False {
+    type Output = Rhs;
+}
+
+/// Type-level logical negation.
+pub trait Not {
+    /// Result of the negation
+    type Output: Bool;
+}
+impl Not for True {
+    type Output = False;
+}
+impl Not for False {
+    type Output = True;
+}
+
+/// Type-level conditional selection, implemented for [True](struct.True.html) and
+/// [False](struct.False.html).
+///
+/// Typically used via the [If](type.If.html) type alias.
+pub trait IfElse<Then, Else> {
+    /// `Then` if this type is [True](struct.True.html), `Else` otherwise
+    type Output;
+}
+impl<Then, Else> IfElse<Then, Else> for True {
+    type Output = Then;
+}
+impl<Then, Else> IfElse<Then, Else> for False {
+    type Output = Else;
+}
+
+/// Type-level conditional: resolves to `Then` if `Cond` is [True](struct.True.html), and `Else` if
+/// `Cond` is [False](struct.False.html).
+///
+/// ## Example
+/// ```
+/// # #[macro_use] extern crate lhlist;
+/// use lhlist::{If, LabelEq, Member, Or};
+/// # fn main() {
+/// new_label![Label1: u8];
+/// new_label![Label2: u16];
+/// new_label![Label3: u32];
+///
+/// // a type-level predicate built from combinators
+/// type HasEither<List, A, B> =
+///     <<List as Member<A>>::Output as Or<<List as Member<B>>::Output>>::Output;
+///
+/// type MyLabels = Labels![Label1, Label2];
+/// let width: If<HasEither<MyLabels, Label2, Label3>, u16, u8> = 500u16;
+/// # let _ = width;
+/// # }
+/// ```
+pub type If<Cond, Then, Else> = <Cond as IfElse<Then, Else>>::Output;
+
 /// Label equality.
 ///
 /// Checks whether two types that implement [Label](trait.Label.html) are the same.
@@ -62,29 +142,14 @@ impl<TargetL> Member<TargetL> for Nil {
 impl<TargetL, L, T> Member<TargetL> for Cons<L, T>
 where
     L: Label + LabelEq<TargetL>,
-    Self: MemberMatch<TargetL, <L as LabelEq<TargetL>>::Output>,
-{
-    type Output = <Self as MemberMatch<TargetL, <L as LabelEq<TargetL>>::Output>>::Output;
-}
-
-/// Helper trait for [Member](trait.Member.html).
-pub trait MemberMatch<L, HeadMatch> {
-    /// [True](struct.True.html) if `TargetL` is a member, [False](struct.False.html) otherwise.
-    type Output: Bool;
-}
-
-impl<TargetL, L, T> MemberMatch<TargetL, True> for Cons<L, T> {
-    type Output = True;
-}
-impl<TargetL, L, T> MemberMatch<TargetL, False> for Cons<L, T>
-where
-    L: Label,
     T: Member<TargetL>,
+    <L as LabelEq<TargetL>>::Output: Or<<T as Member<TargetL>>::Output>,
 {
-    type Output = <T as Member<TargetL>>::Output;
+    type Output = <<L as LabelEq<TargetL>>::Output as Or<<T as Member<TargetL>>::Output>>::Output;
 }
 
 #[cfg(test)]
+#[allow(clippy::assertions_on_constants)]
 mod tests {
     use crate::*;
 
@@ -113,6 +178,26 @@ mod tests {
         assert!(!<Label3 as LabelEq<Label2>>::Output::VALUE);
     }
 
+    #[test]
+    fn combinators() {
+        assert!(<True as And<True>>::Output::VALUE);
+        assert!(!<True as And<False>>::Output::VALUE);
+        assert!(!<False as And<True>>::Output::VALUE);
+        assert!(!<False as And<False>>::Output::VALUE);
+
+        assert!(<True as Or<True>>::Output::VALUE);
+        assert!(<True as Or<False>>::Output::VALUE);
+        assert!(<False as Or<True>>::Output::VALUE);
+        assert!(!<False as Or<False>>::Output::VALUE);
+
+        assert!(!<True as Not>::Output::VALUE);
+        assert!(<False as Not>::Output::VALUE);
+
+        assert!(<If<True, True, False>>::VALUE);
+        assert!(!<If<False, True, False>>::VALUE);
+        assert!(<If<<Label1 as LabelEq<Label1>>::Output, True, False>>::VALUE);
+    }
+
     #[test]
     fn member() {
         // type-based member testing
@@ -0,0 +1,173 @@
+use crate::cons::{Cons, Nil};
+
+/// Reverses the order of the elements of a list.
+///
+/// The reversed list has the same length as the original list (see [LenSize](trait.LenSize.html)).
+pub trait Reverse {
+    /// The reversed list
+    type Output;
+    /// Reverses this list
+    fn reverse(self) -> Self::Output;
+}
+
+impl<T> Reverse for T
+where
+    T: ReverseOnto<Nil>,
+{
+    type Output = <T as ReverseOnto<Nil>>::Output;
+
+    fn reverse(self) -> Self::Output {
+        self.reverse_onto(Nil)
+    }
+}
+
+/// Helper trait for [Reverse](trait.Reverse.html). Prepends the elements of a list, in reverse
+/// order, onto the list `Acc`.
+pub trait ReverseOnto<Acc> {
+    /// The resulting list
+    type Output;
+    /// Prepends the elements of this list in reverse order onto `acc`
+    fn reverse_onto(self, acc: Acc) -> Self::Output;
+}
+
+impl<Acc> ReverseOnto<Acc> for Nil {
+    type Output = Acc;
+
+    fn reverse_onto(self, acc: Acc) -> Acc {
+        acc
+    }
+}
+
+impl<Acc, H, T> ReverseOnto<Acc> for Cons<H, T>
+where
+    T: ReverseOnto<Cons<H, Acc>>,
+{
+    type Output = <T as ReverseOnto<Cons<H, Acc>>>::Output;
+
+    fn reverse_onto(self, acc: Acc) -> Self::Output {
+        self.tail.reverse_onto(Cons {
+            head: self.head,
+            tail: acc,
+        })
+    }
+}
+
+/// Provides access to the last element of a non-empty list.
+pub trait Last {
+    /// The type of the last element
+    type Output;
+    /// Returns a reference to the last element of this list
+    fn last(&self) -> &Self::Output;
+    /// Returns a mutable reference to the last element of this list
+    fn last_mut(&mut self) -> &mut Self::Output;
+}
+
+impl<H> Last for Cons<H, Nil> {
+    type Output = H;
+
+    fn last(&self) -> &H {
+        &self.head
+    }
+    fn last_mut(&mut self) -> &mut H {
+        &mut self.head
+    }
+}
+
+impl<H, NextH, NextT> Last for Cons<H, Cons<NextH, NextT>>
+where
+    Cons<NextH, NextT>: Last,
+{
+    type Output = <Cons<NextH, NextT> as Last>::Output;
+
+    fn last(&self) -> &Self::Output {
+        self.tail.last()
+    }
+    fn last_mut(&mut self) -> &mut Self::Output {
+        self.tail.last_mut()
+    }
+}
+
+/// Removes the last element of a non-empty list, returning the list of all other elements.
+pub trait Init {
+    /// The list of all elements except the last
+    type Output;
+    /// Returns the list of all elements of this list except the last
+    fn init(self) -> Self::Output;
+}
+
+impl<H> Init for Cons<H, Nil> {
+    type Output = Nil;
+
+    fn init(self) -> Nil {
+        Nil
+    }
+}
+
+impl<H, NextH, NextT> Init for Cons<H, Cons<NextH, NextT>>
+where
+    Cons<NextH, NextT>: Init,
+{
+    type Output = Cons<H, <Cons<NextH, NextT> as Init>::Output>;
+
+    fn init(self) -> Self::Output {
+        Cons {
+            head: self.head,
+            tail: self.tail.init(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use typenum::Unsigned;
+
+    use crate::*;
+
+    #[label(type=u8, crate=crate)]
+    struct Label1;
+    #[label(type=String, crate=crate)]
+    struct Label2;
+    #[label(type=bool, crate=crate)]
+    struct Label3;
+
+    #[test]
+    fn reverse() {
+        let list = cons![8, "Hello!", 4.5];
+        assert_eq!(list.clone().reverse(), cons![4.5, "Hello!", 8]);
+        assert_eq!(list.clone().reverse().reverse(), list);
+        assert_eq!(cons![1].reverse(), cons![1]);
+        assert_eq!(Nil.reverse(), Nil);
+
+        fn len<A, B>(_a: &A, _b: &B) -> usize
+        where
+            A: LenSize,
+            B: LenSize<Size = A::Size>,
+        {
+            A::Size::to_usize()
+        }
+        assert_eq!(len(&list, &list.clone().reverse()), 3);
+
+        let list = lhlist![Label1 = 4, Label2 = "foo".to_string(), Label3 = true];
+        let reversed = list.reverse();
+        assert_eq!(reversed.labels(), vec!["Label3", "Label2", "Label1"]);
+        assert_eq!(reversed[Label2], "foo");
+    }
+
+    #[test]
+    fn last_init() {
+        let mut list = lhlist![Label1 = 4, Label2 = "foo".to_string(), Label3 = true];
+        assert!(list.last().value);
+        list.last_mut().value = false;
+        assert!(!list[Label3]);
+
+        let init = list.init();
+        assert_eq!(init, lhlist![Label1 = 4, Label2 = "foo".to_string()]);
+        assert_eq!(init.last().value, "foo");
+        let init = init.init().init();
+        assert_eq!(init, Nil);
+
+        let mut list = cons![1];
+        *list.last_mut() += 1;
+        assert_eq!(list.last(), &2);
+    }
+}
@@ -0,0 +1,219 @@
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+use crate::cons::{Cons, Nil};
+use crate::label::Label;
+
+/// Runtime description of a single label in a cons-list.
+///
+/// Schema fields are compared (and hashed) by `name` and `type_name` only, since label identifiers
+/// are only guaranteed to be consistent within a single compilation.
+#[derive(Debug, Clone)]
+pub struct SchemaField {
+    /// Name of the label (see [Label::NAME](trait.Label.html#associatedconstant.NAME))
+    pub name: &'static str,
+    /// Unique identifier of the label (see [Label::id](trait.Label.html#method.id))
+    pub id: usize,
+    /// Name of the label's associated type, as returned by `std::any::type_name`
+    pub type_name: &'static str,
+}
+
+impl SchemaField {
+    /// Creates the schema field describing label `L`.
+    pub fn of<L>() -> SchemaField
+    where
+        L: Label,
+    {
+        SchemaField {
+            name: L::NAME,
+            id: L::id(),
+            type_name: std::any::type_name::<L::AssocType>(),
+        }
+    }
+}
+
+impl PartialEq for SchemaField {
+    fn eq(&self, other: &SchemaField) -> bool {
+        self.name == other.name && self.type_name == other.type_name
+    }
+}
+impl Eq for SchemaField {}
+impl Hash for SchemaField {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.name.hash(state);
+        self.type_name.hash(state);
+    }
+}
+
+impl fmt::Display for SchemaField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.name, self.type_name)
+    }
+}
+
+/// Runtime description of the layout of a labeled cons-list.
+///
+/// A `Schema` lists the name, identifier, and associated type name of each label in a list, in list
+/// order. It is typically generated using [HasSchema](trait.HasSchema.html).
+///
+/// Two schemas are equal if they have the same label names and associated type names in the same
+/// order, so schemas can be compared to check that two programs agree on a record layout. Label
+/// identifiers are assigned at compile time and are not compared.
+///
+/// ## Example
+/// ```
+/// # #[macro_use] extern crate lhlist;
+/// use lhlist::HasSchema;
+/// # fn main() {
+/// new_label![Name: String];
+/// new_label![Age: u8];
+///
+/// let list = lhlist![Name = "Jane".to_string(), Age = 37];
+/// let schema = list.schema();
+/// assert_eq!(schema.names(), vec!["Name", "Age"]);
+/// assert_eq!(schema.type_names(), vec!["alloc::string::String", "u8"]);
+/// assert_eq!(schema.to_string(), "{Name: alloc::string::String, Age: u8}");
+///
+/// // label-only lists produce the same schema
+/// assert_eq!(schema, <Labels![Name, Age]>::static_schema());
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, Hash, PartialEq, Eq)]
+pub struct Schema {
+    fields: Vec<SchemaField>,
+}
+
+impl Schema {
+    /// Returns the fields of this schema, in list order.
+    pub fn fields(&self) -> &[SchemaField] {
+        &self.fields
+    }
+    /// Returns the number of fields in this schema.
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+    /// Returns `true` if this schema has no fields.
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+    /// Returns the label names of this schema, in list order.
+    pub fn names(&self) -> Vec<&'static str> {
+        self.fields.iter().map(|field| field.name).collect()
+    }
+    /// Returns the associated type names of this schema, in list order.
+    pub fn type_names(&self) -> Vec<&'static str> {
+        self.fields.iter().map(|field| field.type_name).collect()
+    }
+    /// Returns the field with the specified label name, if it exists.
+    pub fn field(&self, name: &str) -> Option<&SchemaField> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+}
+
+impl fmt::Display for Schema {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{{")?;
+        for (i, field) in self.fields.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", field)?;
+        }
+        write!(f, "}}")
+    }
+}
+
+/// Generate a [Schema](struct.Schema.html) describing the labels in a cons-list.
+///
+/// Implemented for both label-only ([LCons](type.LCons.html)) and label-value
+/// ([LVCons](type.LVCons.html)) cons-lists.
+pub trait HasSchema {
+    /// Generates the schema of this list type
+    fn static_schema() -> Schema;
+    /// Generates the schema using a value
+    fn schema(&self) -> Schema {
+        Self::static_schema()
+    }
+}
+impl HasSchema for Nil {
+    fn static_schema() -> Schema {
+        Schema::default()
+    }
+}
+impl<Lbl, Tail> HasSchema for Cons<Lbl, Tail>
+where
+    Self: BuildSchema,
+{
+    fn static_schema() -> Schema {
+        let mut fields = vec![];
+        Self::build_schema(&mut fields);
+        Schema { fields }
+    }
+}
+
+pub trait BuildSchema {
+    fn build_schema(fields: &mut Vec<SchemaField>);
+}
+impl BuildSchema for Nil {
+    fn build_schema(_fields: &mut Vec<SchemaField>) {}
+}
+impl<Lbl, Tail> BuildSchema for Cons<Lbl, Tail>
+where
+    Lbl: Label,
+    Tail: BuildSchema,
+{
+    fn build_schema(fields: &mut Vec<SchemaField>) {
+        fields.push(SchemaField::of::<Lbl>());
+        Tail::build_schema(fields);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[label(type=u8, crate=crate)]
+    struct Label1;
+    #[label(type=Vec<f64>, crate=crate)]
+    struct Label2;
+    #[label(name="Third", crate=crate)]
+    struct Label3;
+
+    #[test]
+    fn schema() {
+        let list = lhlist![Label1 = 3, Label2 = vec![1.0], Label3 = ()];
+        let schema = list.schema();
+        assert_eq!(schema.len(), 3);
+        assert_eq!(schema.names(), vec!["Label1", "Label2", "Third"]);
+        assert_eq!(
+            schema.type_names(),
+            vec!["u8", "alloc::vec::Vec<f64>", "()"]
+        );
+        assert_eq!(schema.field("Label1").unwrap().id, Label1::id());
+        assert_eq!(schema.field("Third").unwrap().id, Label3::id());
+        assert!(schema.field("Label3").is_none());
+        assert_eq!(
+            schema.to_string(),
+            "{Label1: u8, Label2: alloc::vec::Vec<f64>, Third: ()}"
+        );
+
+        assert_eq!(schema, <Labels![Label1, Label2, Label3]>::static_schema());
+        assert_ne!(schema, <Labels![Label2, Label1, Label3]>::static_schema());
+
+        // labels declared separately with the same names and types describe the same layout
+        fn other_schema() -> Schema {
+            #[label(type=u8, crate=crate)]
+            struct Label1;
+            #[label(type=Vec<f64>, crate=crate)]
+            struct Label2;
+            #[label(name="Third", crate=crate)]
+            struct Label3;
+            <Labels![Label1, Label2, Label3]>::static_schema()
+        }
+        assert_eq!(schema, other_schema());
+        assert_ne!(schema.fields()[0].id, other_schema().fields()[0].id);
+
+        assert!(Nil.schema().is_empty());
+        assert_eq!(Nil.schema().to_string(), "{}");
+    }
+}
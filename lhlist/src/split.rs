@@ -0,0 +1,136 @@
+use std::ops::Sub;
+
+use typenum::{Sub1, UInt, UTerm, B1};
+
+use crate::cons::{Cons, Nil};
+use crate::lookup::IndexOf;
+
+/// Splits a list into two lists at a specific position.
+///
+/// `N` is a [typenum](https://docs.rs/typenum) unsigned integer (e.g. `typenum::U2`) denoting the
+/// number of elements in the first list. The second list contains the remaining elements. This
+/// trait is not implemented for positions larger than the length of the list.
+///
+/// To split a list at the position of a specific label, see [SplitAtLabel](trait.SplitAtLabel.html).
+pub trait SplitAt<N> {
+    /// The list of the first `N` elements
+    type Left;
+    /// The list of the remaining elements
+    type Right;
+    /// Splits this list, returning the first `N` elements and the remaining elements
+    fn split_at(self) -> (Self::Left, Self::Right);
+}
+
+impl SplitAt<UTerm> for Nil {
+    type Left = Nil;
+    type Right = Nil;
+
+    fn split_at(self) -> (Nil, Nil) {
+        (Nil, Nil)
+    }
+}
+
+impl<H, T> SplitAt<UTerm> for Cons<H, T> {
+    type Left = Nil;
+    type Right = Self;
+
+    fn split_at(self) -> (Nil, Self) {
+        (Nil, self)
+    }
+}
+
+impl<H, T, U, B> SplitAt<UInt<U, B>> for Cons<H, T>
+where
+    UInt<U, B>: Sub<B1>,
+    T: SplitAt<Sub1<UInt<U, B>>>,
+{
+    type Left = Cons<H, <T as SplitAt<Sub1<UInt<U, B>>>>::Left>;
+    type Right = <T as SplitAt<Sub1<UInt<U, B>>>>::Right;
+
+    fn split_at(self) -> (Self::Left, Self::Right) {
+        let (left, right) = SplitAt::<Sub1<UInt<U, B>>>::split_at(self.tail);
+        (
+            Cons {
+                head: self.head,
+                tail: left,
+            },
+            right,
+        )
+    }
+}
+
+/// Splits a list into two lists at the position of a specific label.
+///
+/// The first list contains the elements before the element labeled by `TargetL`, and the second
+/// list starts with that element. This trait is not implemented for lists which do not contain
+/// `TargetL`.
+pub trait SplitAtLabel<TargetL> {
+    /// The list of the elements before `TargetL`
+    type Left;
+    /// The list of the elements starting at `TargetL`
+    type Right;
+    /// Splits this list, returning the elements before `TargetL` and the remaining elements
+    fn split_at_label(self) -> (Self::Left, Self::Right);
+}
+
+impl<TargetL, H, T> SplitAtLabel<TargetL> for Cons<H, T>
+where
+    Self: IndexOf<TargetL> + SplitAt<<Self as IndexOf<TargetL>>::Output>,
+{
+    type Left = <Self as SplitAt<<Self as IndexOf<TargetL>>::Output>>::Left;
+    type Right = <Self as SplitAt<<Self as IndexOf<TargetL>>::Output>>::Right;
+
+    fn split_at_label(self) -> (Self::Left, Self::Right) {
+        SplitAt::<<Self as IndexOf<TargetL>>::Output>::split_at(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use typenum::{U0, U1, U2, U3};
+
+    use crate::*;
+
+    #[label(type=u8, crate=crate)]
+    struct Label1;
+    #[label(type=String, crate=crate)]
+    struct Label2;
+    #[label(type=bool, crate=crate)]
+    struct Label3;
+
+    #[test]
+    fn split_at() {
+        let list = cons![8, "Hello!", 4.5];
+        assert_eq!(
+            list.clone().split_at::<U0>(),
+            (Nil, cons![8, "Hello!", 4.5])
+        );
+        assert_eq!(
+            list.clone().split_at::<U1>(),
+            (cons![8], cons!["Hello!", 4.5])
+        );
+        assert_eq!(
+            list.clone().split_at::<U3>(),
+            (cons![8, "Hello!", 4.5], Nil)
+        );
+
+        assert_eq!(list.clone().take::<U2>(), cons![8, "Hello!"]);
+        assert_eq!(list.clone().take::<U0>(), Nil);
+        assert_eq!(list.clone().skip::<U2>(), cons![4.5]);
+        assert_eq!(list.skip::<U3>(), Nil);
+    }
+
+    #[test]
+    fn split_at_label() {
+        let list = lhlist![Label1 = 4, Label2 = "foo".to_string(), Label3 = true];
+
+        let (key, payload) = list.clone().split_at_label::<Label2>();
+        assert_eq!(key, lhlist![Label1 = 4]);
+        assert_eq!(payload, lhlist![Label2 = "foo".to_string(), Label3 = true]);
+        assert_eq!(payload.labels(), vec!["Label2", "Label3"]);
+
+        let (key, payload) = list.split_at_label::<Label1>();
+        assert_eq!(key, Nil);
+        assert_eq!(payload.labels(), vec!["Label1", "Label2", "Label3"]);
+    }
+}
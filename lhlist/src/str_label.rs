@@ -0,0 +1,238 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use typenum::Unsigned;
+
+use crate::cons::{Cons, Nil};
+use crate::defaults::LabelDefault;
+use crate::dynamic::LabelFromStr;
+use crate::label::Label;
+use crate::validate::LabelValidate;
+
+/// Maximum length (in bytes) of the name of a [StrLabel](struct.StrLabel.html).
+///
+/// Longer names fail to compile:
+/// ```compile_fail
+/// # #[macro_use] extern crate lhlist;
+/// # fn main() {
+/// let list = lhlist!["aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa" = 1];
+/// # }
+/// ```
+///
+/// ```compile_fail
+/// # #[macro_use] extern crate lhlist;
+/// # fn main() {
+/// let list = lhlist!["ééééééééééééééééééééééééééééééééa" = 1];
+/// # }
+/// ```
+pub const MAX_STR_LABEL_LEN: usize = 64;
+
+/// Type-level character, used to encode the names of [StrLabel](struct.StrLabel.html)s.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Default)]
+pub struct Ch<const C: char>;
+
+/// Type-level string, used to encode the names of [StrLabel](struct.StrLabel.html)s.
+///
+/// `Uid` is a unique identifier derived from the string (see [Label](trait.Label.html)), and
+/// `Chars` is a cons-list of [Ch](struct.Ch.html) characters.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq, Default)]
+pub struct TStr<Uid, Chars>(PhantomData<(Uid, Chars)>);
+
+/// Trait for type-level strings.
+pub trait TypeStr {
+    /// The string's value
+    const VALUE: &'static str;
+    /// Unique identifier for this string
+    type Uid: Unsigned;
+}
+
+impl<Uid, Chars> TypeStr for TStr<Uid, Chars>
+where
+    Uid: Unsigned,
+    Chars: TypeChars,
+{
+    const VALUE: &'static str = bytes_to_str(&Chars::BYTES, Chars::LEN);
+    type Uid = Uid;
+}
+
+const fn bytes_to_str(bytes: &'static [u8; MAX_STR_LABEL_LEN], len: usize) -> &'static str {
+    let (bytes, _) = bytes.split_at(len);
+    match std::str::from_utf8(bytes) {
+        Ok(s) => s,
+        Err(_) => panic!("invalid type-level string"),
+    }
+}
+
+pub trait TypeChars {
+    const LEN: usize;
+    const BYTES: [u8; MAX_STR_LABEL_LEN];
+}
+impl TypeChars for Nil {
+    const LEN: usize = 0;
+    const BYTES: [u8; MAX_STR_LABEL_LEN] = [0; MAX_STR_LABEL_LEN];
+}
+impl<const C: char, T> TypeChars for Cons<Ch<C>, T>
+where
+    T: TypeChars,
+{
+    const LEN: usize = C.len_utf8() + T::LEN;
+    const BYTES: [u8; MAX_STR_LABEL_LEN] = prepend_char(C, &T::BYTES, T::LEN);
+}
+
+const fn prepend_char(
+    c: char,
+    tail: &[u8; MAX_STR_LABEL_LEN],
+    tail_len: usize,
+) -> [u8; MAX_STR_LABEL_LEN] {
+    let mut encoded = [0u8; 4];
+    let char_len = c.encode_utf8(&mut encoded).len();
+    let mut output = [0u8; MAX_STR_LABEL_LEN];
+    let mut i = 0;
+    while i < char_len {
+        output[i] = encoded[i];
+        i += 1;
+    }
+    let mut j = 0;
+    while j < tail_len {
+        output[char_len + j] = tail[j];
+        j += 1;
+    }
+    output
+}
+
+/// An anonymous label identified by a string, with associated type `T`.
+///
+/// Rather than being declared, string labels are written as string literals in the
+/// [lhlist](macro.lhlist.html) macro (and other list macros). The name is encoded at the type
+/// level, so two uses of the same string literal refer to the same label, even across modules and
+/// crates. The name of the label (`Label::NAME`) is the string literal.
+///
+/// The type of a string label can be written using the [StrLabel](macro.StrLabel.html) macro.
+/// Since label equality only depends on the label name, a string label of any associated type can
+/// be used to access an element.
+///
+/// ## Example
+/// ```
+/// # #[macro_use] extern crate lhlist;
+/// use lhlist::{Label, StrLabels};
+/// # fn main() {
+/// let list = lhlist!["name" = "Jane", "age" = 37u8];
+/// assert_eq!(list.labels(), vec!["name", "age"]);
+/// assert_eq!(list.value::<StrLabel!["age"]>(), &37);
+/// assert_eq!(<StrLabel!["name"]>::name(), "name");
+///
+/// fn age(record: &LHList![StrLabel!["name": &str], StrLabel!["age": u8]]) -> u8 {
+///     *record.value::<StrLabel!["age"]>()
+/// }
+/// assert_eq!(age(&list), 37);
+/// # }
+/// ```
+pub struct StrLabel<Name, T = ()>(PhantomData<(Name, fn() -> T)>);
+
+impl<Name, T> Label for StrLabel<Name, T>
+where
+    Name: TypeStr,
+{
+    const NAME: &'static str = Name::VALUE;
+    type AssocType = T;
+    type Uid = Name::Uid;
+}
+
+impl<Name, T> LabelDefault for StrLabel<Name, T>
+where
+    Name: TypeStr,
+    T: Default,
+{
+    fn default_value() -> T {
+        T::default()
+    }
+}
+
+impl<Name, T> LabelValidate for StrLabel<Name, T> where Name: TypeStr {}
+
+impl<Name, T> LabelFromStr for StrLabel<Name, T>
+where
+    Name: TypeStr,
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    fn parse_value(s: &str) -> Option<Result<T, String>> {
+        Some(s.parse().map_err(|err: T::Err| err.to_string()))
+    }
+}
+
+impl_label_traits!(StrLabel<Name, T>);
+
+/// Macro for creating the type of a [StrLabel](struct.StrLabel.html) string label.
+///
+/// `StrLabel!["name": T]` is the type of the label `"name"` with associated type `T`, while
+/// `StrLabel!["name"]` uses associated type `()` (which can still be used for lookups, since label
+/// equality only depends on the name).
+#[macro_export]
+macro_rules! StrLabel {
+    ($($tokens:tt)*) => ( $crate::__StrLabel![$crate; $($tokens)*] );
+}
+
+#[cfg(test)]
+#[allow(clippy::assertions_on_constants)]
+mod tests {
+    use crate::*;
+
+    #[label(name="name", type=u8, crate=crate)]
+    struct Name;
+
+    #[test]
+    fn str_label() {
+        type Age = StrLabel!["age": u8];
+        type Unicode = StrLabel!["ünïcødé ✓"];
+        assert_eq!(Age::name(), "age");
+        assert_eq!(Unicode::name(), "ünïcødé ✓");
+        assert!(<Age as LabelEq<StrLabel!["age": String]>>::Output::VALUE);
+        assert!(!<Age as LabelEq<StrLabel!["ages"]>>::Output::VALUE);
+        assert!(!<Age as LabelEq<Unicode>>::Output::VALUE);
+        assert!(!<Name as LabelEq<StrLabel!["name"]>>::Output::VALUE);
+
+        let mut list = lhlist!["name" = "Jane".to_string(), "age" = 37u8, Name = 4];
+        assert_eq!(list.labels(), vec!["name", "age", "name"]);
+        assert_eq!(list.value::<Age>(), &37);
+        assert_eq!(list.value::<StrLabel!["name"]>(), "Jane");
+        assert_eq!(list.value::<Name>(), &4);
+        *list.value_mut::<StrLabel!["age"]>() += 1;
+
+        let typed: LHList![StrLabel!["name": String], Age, Name] = list;
+        assert_eq!(
+            typed,
+            lhlist!["name" = "Jane".to_string(), "age" = 38, Name = 4]
+        );
+        assert_eq!(
+            format!("{:?}", typed.elem::<Age>()),
+            "LabeledValue { value: 38 }"
+        );
+        assert_eq!(format!("{:?}", Age::default()), "StrLabel(\"age\")");
+
+        let_lhlist! { { "age": age, Name: n, .. } = typed; }
+        assert_eq!((age, n), (38, 4));
+        assert_eq!(
+            <Labels!["name": String, "age"]>::static_labels(),
+            vec!["name", "age"]
+        );
+    }
+
+    #[test]
+    fn str_label_max_len() {
+        type Ascii = StrLabel!["aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa"];
+        type Unicode = StrLabel!["éééééééééééééééééééééééééééééé✓b"];
+        assert_eq!(Ascii::name().len(), MAX_STR_LABEL_LEN);
+        assert_eq!(Unicode::name().len(), MAX_STR_LABEL_LEN);
+        assert_eq!(Unicode::name(), "éééééééééééééééééééééééééééééé✓b");
+        assert!(!<Ascii as LabelEq<Unicode>>::Output::VALUE);
+
+        let list = lhlist![
+            "aaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaaa" = 1u8,
+            "éééééééééééééééééééééééééééééé✓b" = 2u8
+        ];
+        assert_eq!(list.value::<Ascii>(), &1);
+        assert_eq!(list.value::<Unicode>(), &2);
+    }
+}
@@ -0,0 +1,183 @@
+use crate::cons::{Cons, LCons, LVCons, Nil};
+use crate::label::{Label, LabeledValue};
+use crate::relation::{False, LabelEq, Member, True};
+
+/// Replaces the element labeled by label `Old` with an element labeled by label `New`, whose value
+/// is computed from the old value. All other elements are left as-is.
+///
+/// `New` must not label any other element of the list, so that the resulting list doesn't contain
+/// duplicate labels.
+pub trait Replace<Old, New>
+where
+    New: Label,
+{
+    /// The type of the value being replaced
+    type Value;
+    /// The type of the list after replacement
+    type Output;
+    /// Replaces the element, computing the new value with `f`
+    fn replace<F>(self, f: F) -> Self::Output
+    where
+        F: FnOnce(Self::Value) -> New::AssocType;
+}
+
+impl<Old, New, L, T> Replace<Old, New> for LVCons<L, T>
+where
+    New: Label,
+    L: Label + LabelEq<Old>,
+    Self: ReplaceMatch<Old, New, <L as LabelEq<Old>>::Output>,
+{
+    type Value = <Self as ReplaceMatch<Old, New, <L as LabelEq<Old>>::Output>>::Value;
+    type Output = <Self as ReplaceMatch<Old, New, <L as LabelEq<Old>>::Output>>::Output;
+
+    fn replace<F>(self, f: F) -> Self::Output
+    where
+        F: FnOnce(Self::Value) -> New::AssocType,
+    {
+        ReplaceMatch::<Old, New, <L as LabelEq<Old>>::Output>::replace(self, f)
+    }
+}
+
+/// Helper trait for [Replace](trait.Replace.html).
+pub trait ReplaceMatch<Old, New, HeadMatch>
+where
+    New: Label,
+{
+    /// The type of the value being replaced
+    type Value;
+    /// The type of the list after replacement
+    type Output;
+    /// Replaces the element, computing the new value with `f`
+    fn replace<F>(self, f: F) -> Self::Output
+    where
+        F: FnOnce(Self::Value) -> New::AssocType;
+}
+
+// head matches; `New` must not be in the tail
+impl<Old, New, L, T> ReplaceMatch<Old, New, True> for LVCons<L, T>
+where
+    New: Label,
+    L: Label,
+    T: Member<New, Output = False>,
+{
+    type Value = L::AssocType;
+    type Output = LVCons<New, T>;
+
+    fn replace<F>(self, f: F) -> Self::Output
+    where
+        F: FnOnce(Self::Value) -> New::AssocType,
+    {
+        Cons {
+            head: LabeledValue::new(f(self.head.value)),
+            tail: self.tail,
+        }
+    }
+}
+
+// head doesn't match, look in tail; the head must not be labeled `New`
+impl<Old, New, L, T> ReplaceMatch<Old, New, False> for LVCons<L, T>
+where
+    New: Label,
+    L: Label + LabelEq<New, Output = False>,
+    T: Replace<Old, New>,
+{
+    type Value = <T as Replace<Old, New>>::Value;
+    type Output = LVCons<L, <T as Replace<Old, New>>::Output>;
+
+    fn replace<F>(self, f: F) -> Self::Output
+    where
+        F: FnOnce(Self::Value) -> New::AssocType,
+    {
+        Cons {
+            head: self.head,
+            tail: self.tail.replace(f),
+        }
+    }
+}
+
+/// Moves the values of a label-value cons-list into a new list with the labels in `Labels` (an
+/// [LCons](type.LCons.html) label-only cons-list).
+///
+/// `Labels` must have the same length as the list, must not contain duplicate labels, and the
+/// associated type of each label in `Labels` must be the type of the value it relabels.
+pub trait Relabel<Labels> {
+    /// The relabeled list
+    type Output;
+    /// Relabels the list
+    fn relabel(self) -> Self::Output;
+}
+
+impl Relabel<Nil> for Nil {
+    type Output = Nil;
+
+    fn relabel(self) -> Nil {
+        Nil
+    }
+}
+
+impl<L, T, NewL, NewT> Relabel<LCons<NewL, NewT>> for LVCons<L, T>
+where
+    L: Label,
+    NewL: Label<AssocType = L::AssocType>,
+    NewT: Member<NewL, Output = False>,
+    T: Relabel<NewT>,
+{
+    type Output = LVCons<NewL, <T as Relabel<NewT>>::Output>;
+
+    fn relabel(self) -> Self::Output {
+        Cons {
+            head: LabeledValue::new(self.head.value),
+            tail: self.tail.relabel(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[label(type=Vec<String>, crate=crate)]
+    struct Label1;
+    #[label(type=Vec<u32>, crate=crate)]
+    struct Label1Parsed;
+    #[label(type=bool, crate=crate)]
+    struct Label2;
+    #[label(type=u8, crate=crate)]
+    struct Label3;
+
+    #[test]
+    fn replace() {
+        let list = lhlist![
+            Label2 = true,
+            Label1 = vec!["1".to_string(), "22".to_string()],
+            Label3 = 4
+        ];
+        let list = list.replace::<Label1, Label1Parsed>(|values| {
+            values.iter().map(|value| value.parse().unwrap()).collect()
+        });
+        assert_eq!(list.labels(), vec!["Label2", "Label1Parsed", "Label3"]);
+        assert_eq!(
+            list,
+            lhlist![Label2 = true, Label1Parsed = vec![1, 22], Label3 = 4]
+        );
+
+        let list = list.replace::<Label3, StrLabel!["doubled": u16]>(|value| value as u16 * 2);
+        assert_eq!(list.value::<StrLabel!["doubled"]>(), &8);
+        assert_eq!(list.labels(), vec!["Label2", "Label1Parsed", "doubled"]);
+
+        // replacing a label with itself is allowed
+        let list = list.replace::<Label2, Label2>(|value| !value);
+        assert!(!list[Label2]);
+    }
+
+    #[test]
+    fn relabel() {
+        let list = lhlist![Label2 = true, Label3 = 4];
+        let list = list.relabel::<Labels![StrLabel!["flag": bool], StrLabel!["count": u8]]>();
+        assert_eq!(list.labels(), vec!["flag", "count"]);
+        assert_eq!(list.value::<StrLabel!["count"]>(), &4);
+
+        let list = list.relabel::<Labels![Label2, Label3]>();
+        assert_eq!(list, lhlist![Label2 = true, Label3 = 4]);
+    }
+}
@@ -0,0 +1,297 @@
+use std::error::Error;
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::cons::{Cons, LVCons, Nil};
+use crate::defaults::LabelDefault;
+use crate::dynamic::LabelFromStr;
+use crate::label::{Label, LabeledValue};
+use crate::validate::LabelValidate;
+
+/// Trait for wrapper types (such as `Option` and `Result`) which may contain a value.
+pub trait Wrapped {
+    /// The type of the wrapped value
+    type Inner;
+    /// Wraps a value
+    fn wrap(inner: Self::Inner) -> Self;
+    /// Returns the wrapped value, if any
+    fn into_inner(self) -> Option<Self::Inner>;
+}
+impl<T> Wrapped for Option<T> {
+    type Inner = T;
+    fn wrap(inner: T) -> Self {
+        Some(inner)
+    }
+    fn into_inner(self) -> Option<T> {
+        self
+    }
+}
+impl<T, E> Wrapped for Result<T, E> {
+    type Inner = T;
+    fn wrap(inner: T) -> Self {
+        Ok(inner)
+    }
+    fn into_inner(self) -> Option<T> {
+        self.ok()
+    }
+}
+
+/// Label derived from a label whose associated type is a [Wrapped](trait.Wrapped.html) type (such
+/// as `Option<T>` or `Result<T, E>`), with the wrapped type `T` as its associated type.
+///
+/// `Unwrapped<L>` has the same name and identifier as `L`, so elements labeled with `Unwrapped<L>`
+/// can still be accessed using `L`.
+pub struct Unwrapped<L>(PhantomData<L>);
+
+impl<L> Label for Unwrapped<L>
+where
+    L: Label,
+    L::AssocType: Wrapped,
+{
+    const NAME: &'static str = L::NAME;
+    type AssocType = <L::AssocType as Wrapped>::Inner;
+    type Uid = L::Uid;
+}
+
+impl<L> LabelDefault for Unwrapped<L>
+where
+    L: Label,
+    L::AssocType: Wrapped,
+    <L::AssocType as Wrapped>::Inner: Default,
+{
+    fn default_value() -> Self::AssocType {
+        Default::default()
+    }
+}
+
+// unwrapped values are validated by the original label, after wrapping a copy of the value
+impl<L> LabelValidate for Unwrapped<L>
+where
+    L: LabelValidate,
+    L::AssocType: Wrapped,
+    <L::AssocType as Wrapped>::Inner: Clone,
+{
+    fn validate(value: &Self::AssocType) -> Result<(), String> {
+        L::validate(&Wrapped::wrap(value.clone()))
+    }
+}
+
+// unwrapped values are parsed by the original label, and then unwrapped
+impl<L> LabelFromStr for Unwrapped<L>
+where
+    L: LabelFromStr,
+    L::AssocType: Wrapped,
+{
+    fn parse_value(s: &str) -> Option<Result<Self::AssocType, String>> {
+        L::parse_value(s).map(|value| {
+            value.and_then(|value| {
+                value
+                    .into_inner()
+                    .ok_or_else(|| "parsed value is empty".to_string())
+            })
+        })
+    }
+}
+
+impl_label_traits!(Unwrapped<L>);
+
+/// Error produced by [transpose_result](trait.TransposeResult.html#tymethod.transpose_result),
+/// recording which label held the error.
+#[derive(Debug, Clone, Hash, PartialEq, Eq)]
+pub struct LabeledError<E> {
+    /// Name of the label whose value was an error
+    pub label: &'static str,
+    /// The error
+    pub error: E,
+}
+
+impl<E> fmt::Display for LabeledError<E>
+where
+    E: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.label, self.error)
+    }
+}
+
+impl<E> Error for LabeledError<E>
+where
+    E: Error + 'static,
+{
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Converts a labeled cons-list of `Option`s into an `Option` of a labeled cons-list.
+///
+/// Each label `L` in the list is replaced by [`Unwrapped<L>`](struct.Unwrapped.html), whose
+/// associated type is the type contained in the `Option`.
+///
+/// ## Example
+/// ```
+/// # #[macro_use] extern crate lhlist;
+/// use lhlist::TransposeOption;
+/// # fn main() {
+/// new_label![Name: Option<&'static str>];
+/// new_label![Age: Option<u8>];
+///
+/// let list = lhlist![Name = Some("Jane"), Age = Some(37)];
+/// let list = list.transpose_option().unwrap();
+/// assert_eq!(list.value::<Name>(), &"Jane");
+/// assert_eq!(list.value::<Age>(), &37);
+///
+/// assert!(lhlist![Name = Some("Jane"), Age = None].transpose_option().is_none());
+/// # }
+/// ```
+pub trait TransposeOption {
+    /// The resulting labeled cons-list
+    type Output;
+    /// Returns the list of contained values if all values are `Some`, and `None` otherwise.
+    fn transpose_option(self) -> Option<Self::Output>;
+}
+
+impl TransposeOption for Nil {
+    type Output = Nil;
+
+    fn transpose_option(self) -> Option<Nil> {
+        Some(Nil)
+    }
+}
+
+impl<L, V, T> TransposeOption for LVCons<L, T>
+where
+    L: Label<AssocType = Option<V>>,
+    T: TransposeOption,
+{
+    type Output = LVCons<Unwrapped<L>, T::Output>;
+
+    fn transpose_option(self) -> Option<Self::Output> {
+        let head = self.head.value?;
+        let tail = self.tail.transpose_option()?;
+        Some(Cons {
+            head: LabeledValue::new(head),
+            tail,
+        })
+    }
+}
+
+/// Converts a labeled cons-list of `Result`s into a `Result` of a labeled cons-list.
+///
+/// Each label `L` in the list is replaced by [`Unwrapped<L>`](struct.Unwrapped.html), whose
+/// associated type is the `Ok` type of the `Result`. All values must share the error type `E`.
+///
+/// ## Example
+/// ```
+/// # #[macro_use] extern crate lhlist;
+/// use std::num::ParseIntError;
+/// use lhlist::TransposeResult;
+/// # fn main() {
+/// new_label![Width: Result<u32, ParseIntError>];
+/// new_label![Height: Result<u32, ParseIntError>];
+///
+/// let list = lhlist![Width = "640".parse(), Height = "480".parse()];
+/// let list = list.transpose_result().unwrap();
+/// assert_eq!(list.value::<Width>(), &640);
+/// assert_eq!(list.value::<Height>(), &480);
+///
+/// let err = lhlist![Width = "640".parse(), Height = "tall".parse()]
+///     .transpose_result()
+///     .unwrap_err();
+/// assert_eq!(err.label, "Height");
+/// assert_eq!(err.to_string(), "Height: invalid digit found in string");
+/// # }
+/// ```
+pub trait TransposeResult<E> {
+    /// The resulting labeled cons-list
+    type Output;
+    /// Returns the list of contained values if all values are `Ok`, and otherwise the first error
+    /// (in list order) along with the name of its label.
+    fn transpose_result(self) -> Result<Self::Output, LabeledError<E>>;
+}
+
+impl<E> TransposeResult<E> for Nil {
+    type Output = Nil;
+
+    fn transpose_result(self) -> Result<Nil, LabeledError<E>> {
+        Ok(Nil)
+    }
+}
+
+impl<L, V, E, T> TransposeResult<E> for LVCons<L, T>
+where
+    L: Label<AssocType = Result<V, E>>,
+    T: TransposeResult<E>,
+{
+    type Output = LVCons<Unwrapped<L>, T::Output>;
+
+    fn transpose_result(self) -> Result<Self::Output, LabeledError<E>> {
+        let head = self.head.value.map_err(|error| LabeledError {
+            label: L::NAME,
+            error,
+        })?;
+        let tail = self.tail.transpose_result()?;
+        Ok(Cons {
+            head: LabeledValue::new(head),
+            tail,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[label(type=Option<u8>, crate=crate)]
+    struct Label1;
+    #[label(type=Option<String>, crate=crate)]
+    struct Label2;
+    #[label(type=Result<u8, String>, crate=crate)]
+    struct Label3;
+    #[label(type=Result<bool, String>, crate=crate)]
+    struct Label4;
+
+    #[test]
+    fn transpose_option() {
+        let list = lhlist![Label1 = Some(4), Label2 = Some("foo".to_string())];
+        let list = list.transpose_option().unwrap();
+        assert_eq!(list.labels(), vec!["Label1", "Label2"]);
+        assert_eq!(list[Label1], 4);
+        assert_eq!(list.value::<Label2>(), "foo");
+        assert_eq!(list.elem::<Unwrapped<Label1>>().value, 4);
+
+        assert_eq!(
+            lhlist![Label1 = None, Label2 = Some("foo".to_string())].transpose_option(),
+            None
+        );
+        assert_eq!(Nil.transpose_option(), Some(Nil));
+    }
+
+    #[test]
+    fn transpose_result() {
+        let list = lhlist![Label3 = Ok(4), Label4 = Ok(true)];
+        let list = list.transpose_result().unwrap();
+        assert_eq!(list.labels(), vec!["Label3", "Label4"]);
+        assert_eq!(list[Label3], 4);
+        assert!(list[Label4]);
+
+        let err = lhlist![
+            Label3 = Err("bad".to_string()),
+            Label4 = Err("worse".to_string())
+        ]
+        .transpose_result()
+        .unwrap_err();
+        assert_eq!(
+            err,
+            LabeledError {
+                label: "Label3",
+                error: "bad".to_string()
+            }
+        );
+        let err = lhlist![Label3 = Ok(4), Label4 = Err("worse".to_string())]
+            .transpose_result()
+            .unwrap_err();
+        assert_eq!(err.label, "Label4");
+        assert_eq!(err.to_string(), "Label4: worse");
+    }
+}
@@ -0,0 +1,188 @@
+use crate::cons::{LVCons, Nil};
+use crate::label::Label;
+use crate::transpose::LabeledError;
+
+/// Trait for labels which validate their values.
+///
+/// Labels created with the [label](attr.label.html) attribute can specify a validation function
+/// with the `validate` option. The function takes a reference to a value of the label's associated
+/// type, and returns `Result<(), E>` for any error type `E` implementing `ToString`. Labels without
+/// a `validate` option accept all values.
+///
+/// ## Example
+/// ```
+/// # #[macro_use] extern crate lhlist;
+/// use lhlist::{LabeledError, LabelValidate, Validate};
+/// # fn main() {
+/// fn non_empty(name: &String) -> Result<(), &'static str> {
+///     if name.is_empty() {
+///         Err("must not be empty")
+///     } else {
+///         Ok(())
+///     }
+/// }
+/// fn positive(price: &f64) -> Result<(), String> {
+///     if *price > 0.0 {
+///         Ok(())
+///     } else {
+///         Err(format!("must be positive, found {}", price))
+///     }
+/// }
+///
+/// #[label(type=String, validate=non_empty)]
+/// struct Name;
+/// #[label(type=f64, validate=positive)]
+/// struct Price;
+/// #[label(type=bool)]
+/// struct InStock;
+///
+/// assert!(Name::validate(&"Widget".to_string()).is_ok());
+///
+/// let item = lhlist![Name = "Widget".to_string(), Price = 2.5, InStock = true];
+/// assert!(item.validate().is_ok());
+///
+/// let item = lhlist![Name = String::new(), Price = -1.0, InStock = false];
+/// assert_eq!(
+///     item.validate().unwrap_err(),
+///     vec![
+///         LabeledError { label: "Name", error: "must not be empty".to_string() },
+///         LabeledError { label: "Price", error: "must be positive, found -1".to_string() },
+///     ]
+/// );
+/// # }
+/// ```
+pub trait LabelValidate: Label {
+    /// Validates a value for this label, returning an error message if the value is invalid
+    fn validate(_value: &Self::AssocType) -> Result<(), String> {
+        Ok(())
+    }
+}
+
+/// Validates every value in a label-value cons-list using the label's
+/// [LabelValidate](trait.LabelValidate.html) implementation.
+pub trait Validate {
+    /// Adds the failures of all values in this list to `failures`, in list order
+    fn validate_into(&self, failures: &mut Vec<LabeledError<String>>);
+
+    /// Validates all values in this list, returning all failures (in list order) if any value is
+    /// invalid
+    fn validate(&self) -> Result<(), Vec<LabeledError<String>>> {
+        let mut failures = vec![];
+        self.validate_into(&mut failures);
+        if failures.is_empty() {
+            Ok(())
+        } else {
+            Err(failures)
+        }
+    }
+}
+
+impl Validate for Nil {
+    fn validate_into(&self, _failures: &mut Vec<LabeledError<String>>) {}
+}
+
+impl<L, T> Validate for LVCons<L, T>
+where
+    L: LabelValidate,
+    T: Validate,
+{
+    fn validate_into(&self, failures: &mut Vec<LabeledError<String>>) {
+        if let Err(error) = L::validate(&self.head.value) {
+            failures.push(LabeledError {
+                label: L::NAME,
+                error,
+            });
+        }
+        self.tail.validate_into(failures);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn small(value: &u8) -> Result<(), String> {
+        if *value < 10 {
+            Ok(())
+        } else {
+            Err(format!("{} is too large", value))
+        }
+    }
+
+    mod checks {
+        pub fn non_empty(value: &[u8]) -> Result<(), &'static str> {
+            if value.is_empty() {
+                Err("empty")
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    #[label(type=u8, validate=small, crate=crate)]
+    struct Label1;
+    #[label(type=Vec<u8>, validate=checks::non_empty, crate=crate)]
+    struct Label2;
+    #[label(type=bool, crate=crate)]
+    struct Label3;
+    #[label(type=Result<u8, String>, validate=small_ok, crate=crate)]
+    struct Label4;
+
+    fn small_ok(value: &Result<u8, String>) -> Result<(), String> {
+        match value {
+            Ok(value) => small(value),
+            Err(_) => Ok(()),
+        }
+    }
+
+    #[test]
+    fn validate() {
+        assert!(Label1::validate(&4).is_ok());
+        assert_eq!(Label1::validate(&30), Err("30 is too large".to_string()));
+        assert!(Label3::validate(&false).is_ok());
+
+        let list = lhlist![Label1 = 2, Label2 = vec![1], Label3 = true];
+        assert_eq!(list.validate(), Ok(()));
+
+        let list = lhlist![Label1 = 15, Label2 = vec![1], Label3 = true];
+        assert_eq!(
+            list.validate(),
+            Err(vec![LabeledError {
+                label: "Label1",
+                error: "15 is too large".to_string()
+            }])
+        );
+
+        let list = lhlist![Label3 = true, Label2 = vec![], Label1 = 10];
+        let failures = list.validate().unwrap_err();
+        assert_eq!(failures.len(), 2);
+        assert_eq!(failures[0].to_string(), "Label2: empty");
+        assert_eq!(failures[1].to_string(), "Label1: 10 is too large");
+
+        assert_eq!(Nil.validate(), Ok(()));
+    }
+
+    #[test]
+    fn validate_derived_labels() {
+        let list = lhlist![Label4 = Ok(3)].transpose_result().unwrap();
+        assert_eq!(list.validate(), Ok(()));
+        let list = lhlist![Label4 = Ok(12)].transpose_result().unwrap();
+        let failures = list.validate().unwrap_err();
+        assert_eq!(failures[0].to_string(), "Label4: 12 is too large");
+
+        let mut list = lhlist![Label1 = 12, Label2 = vec![]];
+        assert_eq!(list.as_refs().validate().unwrap_err().len(), 2);
+        *list.value_mut::<Label1>() = 1;
+        assert_eq!(
+            list.as_mut_refs().validate(),
+            Err(vec![LabeledError {
+                label: "Label2",
+                error: "empty".to_string()
+            }])
+        );
+
+        let mut partial = Partial::<Labels![Label1, Label2]>::new();
+        partial.set::<Label1>(20);
+        assert_eq!(partial.fields().validate().unwrap_err().len(), 1);
+    }
+}
@@ -0,0 +1,237 @@
+use std::any::Any;
+
+use crate::cons::{LVCons, Nil};
+use crate::label::Label;
+
+/// Trait for types which inspect each value of a labeled cons-list, along with its label.
+///
+/// A visitor is applied to a list with [accept](trait.Accept.html#tymethod.accept), which calls
+/// `visit` for each element of the list in order. Values implement `Any`, so visitors can downcast
+/// them to the types they handle.
+///
+/// ## Example
+/// ```
+/// # #[macro_use] extern crate lhlist;
+/// use std::any::Any;
+/// use lhlist::{Accept, Label, Visitor};
+/// # fn main() {
+/// new_label![Name: &'static str];
+/// new_label![Scores: Vec<u32>];
+///
+/// struct Logger(Vec<String>);
+/// impl Visitor for Logger {
+///     fn visit<L>(&mut self, value: &L::AssocType)
+///     where
+///         L: Label,
+///         L::AssocType: Any,
+///     {
+///         let value = value as &dyn Any;
+///         if let Some(name) = value.downcast_ref::<&str>() {
+///             self.0.push(format!("{}={}", L::NAME, name));
+///         } else if let Some(scores) = value.downcast_ref::<Vec<u32>>() {
+///             self.0.push(format!("{}={:?}", L::NAME, scores));
+///         } else {
+///             self.0.push(format!("{}: {}", L::NAME, std::any::type_name::<L::AssocType>()));
+///         }
+///     }
+/// }
+///
+/// let list = lhlist![Name = "Jane", Scores = vec![6, 8]];
+/// let mut logger = Logger(vec![]);
+/// list.accept(&mut logger);
+/// assert_eq!(logger.0, vec!["Name=Jane", "Scores=[6, 8]"]);
+/// # }
+/// ```
+pub trait Visitor {
+    /// Visits the value labeled by label `L`
+    fn visit<L>(&mut self, value: &L::AssocType)
+    where
+        L: Label,
+        L::AssocType: Any;
+}
+
+/// Trait for types which inspect and modify each value of a labeled cons-list, along with its
+/// label.
+///
+/// A visitor is applied to a list with [accept_mut](trait.Accept.html#tymethod.accept_mut), which
+/// calls `visit_mut` for each element of the list in order. Values implement `Any`, so visitors
+/// can downcast them to the types they modify.
+///
+/// ## Example
+/// ```
+/// # #[macro_use] extern crate lhlist;
+/// use std::any::Any;
+/// use lhlist::{Accept, Label, VisitorMut};
+/// # fn main() {
+/// new_label![Name: String];
+/// new_label![Email: String];
+/// new_label![Age: u8];
+///
+/// struct Trim;
+/// impl VisitorMut for Trim {
+///     fn visit_mut<L>(&mut self, value: &mut L::AssocType)
+///     where
+///         L: Label,
+///         L::AssocType: Any,
+///     {
+///         if let Some(s) = (value as &mut dyn Any).downcast_mut::<String>() {
+///             *s = s.trim().to_string();
+///         }
+///     }
+/// }
+///
+/// let mut list = lhlist![
+///     Name = " Jane ".to_string(),
+///     Email = "jane@example.com\n".to_string(),
+///     Age = 37,
+/// ];
+/// list.accept_mut(&mut Trim);
+/// assert_eq!(
+///     list,
+///     lhlist![Name = "Jane".to_string(), Email = "jane@example.com".to_string(), Age = 37]
+/// );
+/// # }
+/// ```
+pub trait VisitorMut {
+    /// Visits the value labeled by label `L`
+    fn visit_mut<L>(&mut self, value: &mut L::AssocType)
+    where
+        L: Label,
+        L::AssocType: Any;
+}
+
+/// Applies [Visitor](trait.Visitor.html)s and [VisitorMut](trait.VisitorMut.html)s to a labeled
+/// cons-list.
+pub trait Accept {
+    /// Calls `visitor.visit` for each element of this list, in list order
+    fn accept<V: Visitor>(&self, visitor: &mut V);
+    /// Calls `visitor.visit_mut` for each element of this list, in list order
+    fn accept_mut<V: VisitorMut>(&mut self, visitor: &mut V);
+}
+
+impl Accept for Nil {
+    fn accept<V: Visitor>(&self, _visitor: &mut V) {}
+    fn accept_mut<V: VisitorMut>(&mut self, _visitor: &mut V) {}
+}
+
+impl<L, T> Accept for LVCons<L, T>
+where
+    L: Label,
+    L::AssocType: Any,
+    T: Accept,
+{
+    fn accept<V: Visitor>(&self, visitor: &mut V) {
+        visitor.visit::<L>(&self.head.value);
+        self.tail.accept(visitor);
+    }
+    fn accept_mut<V: VisitorMut>(&mut self, visitor: &mut V) {
+        visitor.visit_mut::<L>(&mut self.head.value);
+        self.tail.accept_mut(visitor);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::any::Any;
+    use std::mem::size_of_val;
+
+    use crate::*;
+
+    #[label(type=u8, crate=crate)]
+    struct Label1;
+    #[label(type=[u32; 3], crate=crate)]
+    struct Label2;
+    #[label(name="Third", type=u16, crate=crate)]
+    struct Label3;
+
+    struct Sizes(Vec<(&'static str, usize)>);
+    impl Visitor for Sizes {
+        fn visit<L>(&mut self, value: &L::AssocType)
+        where
+            L: Label,
+            L::AssocType: Any,
+        {
+            self.0.push((L::NAME, size_of_val(value)));
+        }
+    }
+
+    struct Ids(Vec<usize>);
+    impl VisitorMut for Ids {
+        fn visit_mut<L>(&mut self, _value: &mut L::AssocType)
+        where
+            L: Label,
+            L::AssocType: Any,
+        {
+            self.0.push(L::id());
+        }
+    }
+
+    // logs integer values, and the type names of other values
+    struct Logger(Vec<String>);
+    impl Visitor for Logger {
+        fn visit<L>(&mut self, value: &L::AssocType)
+        where
+            L: Label,
+            L::AssocType: Any,
+        {
+            let value = value as &dyn Any;
+            let entry = if let Some(value) = value.downcast_ref::<u8>() {
+                format!("{}={}", L::NAME, value)
+            } else if let Some(value) = value.downcast_ref::<u16>() {
+                format!("{}={}", L::NAME, value)
+            } else {
+                format!("{}: {}", L::NAME, std::any::type_name::<L::AssocType>())
+            };
+            self.0.push(entry);
+        }
+    }
+
+    // doubles integer values
+    struct Doubler;
+    impl VisitorMut for Doubler {
+        fn visit_mut<L>(&mut self, value: &mut L::AssocType)
+        where
+            L: Label,
+            L::AssocType: Any,
+        {
+            let value = value as &mut dyn Any;
+            if let Some(value) = value.downcast_mut::<u8>() {
+                *value *= 2;
+            } else if let Some(value) = value.downcast_mut::<u16>() {
+                *value *= 2;
+            } else if let Some(values) = value.downcast_mut::<[u32; 3]>() {
+                values.iter_mut().for_each(|value| *value *= 2);
+            }
+        }
+    }
+
+    #[test]
+    fn visit() {
+        let mut list = lhlist![Label1 = 4, Label2 = [1, 2, 3], Label3 = 7];
+        let mut sizes = Sizes(vec![]);
+        list.accept(&mut sizes);
+        assert_eq!(sizes.0, vec![("Label1", 1), ("Label2", 12), ("Third", 2)]);
+
+        let mut ids = Ids(vec![]);
+        list.accept_mut(&mut ids);
+        assert_eq!(ids.0, vec![Label1::id(), Label2::id(), Label3::id()]);
+
+        Nil.accept(&mut sizes);
+        assert_eq!(sizes.0.len(), 3);
+    }
+
+    #[test]
+    fn visit_values() {
+        let mut list = lhlist![Label1 = 4, Label2 = [1, 2, 3], Label3 = 7];
+        let mut logger = Logger(vec![]);
+        list.accept(&mut logger);
+        assert_eq!(logger.0, vec!["Label1=4", "Label2: [u32; 3]", "Third=7"]);
+
+        list.accept_mut(&mut Doubler);
+        assert_eq!(list, lhlist![Label1 = 8, Label2 = [2, 4, 6], Label3 = 14]);
+
+        let mut logger = Logger(vec![]);
+        list.accept(&mut logger);
+        assert_eq!(logger.0, vec!["Label1=8", "Label2: [u32; 3]", "Third=14"]);
+    }
+}